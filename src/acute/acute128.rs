@@ -0,0 +1,11 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 128-bit Signed Acute
+/// 
+/// 1 Sign bit, 127 Fraction bits
+/// 
+/// Minimum Decimal: -1
+/// 
+/// Maximum Decimal: 0.9999999999999999999999999999999999999941225282458885624601563173138887716109066722161395623924562414686079137027263641357421875
+pub type a128 = Fixed<u128, 127>;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 16-bit Signed Acute
+/// 
+/// 1 Sign bit, 15 Fraction bits
+/// 
+/// Minimum Decimal: -1
+/// 
+/// Maximum Decimal: 0.999969482421875
+pub type a16 = Fixed<u16, 15>;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 32-bit Signed Acute
+/// 
+/// 1 Sign bit, 31 Fraction bits
+/// 
+/// Minimum Decimal: -1
+/// 
+/// Maximum Decimal: 0.9999999995343387126922607421875
+pub type a32 = Fixed<u32, 31>;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 64-bit Signed Acute
+/// 
+/// 1 Sign bit, 63 Fraction bits
+/// 
+/// Minimum Decimal: -1
+/// 
+/// Maximum Decimal: 0.999999999999999999891579782751449556599254719913005828857421875
+pub type a64 = Fixed<u64, 63>;
\ No newline at end of file
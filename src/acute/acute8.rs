@@ -0,0 +1,11 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 8-bit Signed Acute
+/// 
+/// 1 Sign bit, 7 Fraction bits
+/// 
+/// Minimum Decimal: -1
+/// 
+/// Maximum Decimal: 0.9921875
+pub type a8 = Fixed<u8, 7>;
\ No newline at end of file
@@ -0,0 +1,13 @@
+// Signed Acutes
+pub mod acute8;
+pub mod acute16;
+pub mod acute32;
+pub mod acute64;
+pub mod acute128;
+
+// Unsigned Acutes
+pub mod u_acute8;
+pub mod u_acute16;
+pub mod u_acute32;
+pub mod u_acute64;
+pub mod u_acute128;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 128-bit Unsigned Acute
+/// 
+/// 128 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 0.99999999999999999999999999999999999999706126412294428123007815865694438580545333610806978119622812073430395685136318206787109375
+pub type ua128 = UFixed<u128, 128>;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 16-bit Unsigned Acute
+/// 
+/// 16 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 0.9999847412109375
+pub type ua16 = UFixed<u16, 16>;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 32-bit Unsigned Acute
+/// 
+/// 32 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 0.99999999976716935634613037109375
+pub type ua32 = UFixed<u32, 32>;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 64-bit Unsigned Acute
+/// 
+/// 64 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 0.9999999999999999999457898913757247782996273599565029144287109375
+pub type ua64 = UFixed<u64, 64>;
\ No newline at end of file
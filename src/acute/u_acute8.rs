@@ -0,0 +1,11 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 8-bit Unsigned Acute
+/// 
+/// 8 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 0.99609375
+pub type ua8 = UFixed<u8, 8>;
\ No newline at end of file
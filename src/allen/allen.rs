@@ -1,19 +1,28 @@
 #![allow(dead_code)]
 
 use std::{
-    fmt::{Binary, Formatter, LowerHex, Result, UpperHex},
+    fmt::{Binary, Display, Formatter, LowerHex, Result, UpperHex},
     iter,
-    ops::{Add, Mul, Shl, Shr},
+    ops::{Add, Mul, Shl, Shr, Sub},
     cmp::{Eq, PartialEq, Ordering}
 };
+use crate::rounding::RoundingMode;
 
-
+#[allow(non_camel_case_types)]
 type udef = u8;
+#[allow(non_camel_case_types)]
+type udbl = u16; // Double width of udef
 
 /// @quelfth on discord
 #[derive(Clone, Debug)]
 pub struct BigInt(Vec<udef>);
 
+impl Default for BigInt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl BigInt {
     pub fn new() -> Self {
         Self(Vec::new())
@@ -100,7 +109,7 @@ impl Shl<udef> for BigInt {
                 if ti > 0 {
                     self.0[ti] = (self.0[ti] << shift) | (self.0[ti - 1] >> (bits - shift))
                 } else {
-                    self.0[ti] = self.0[ti] << shift
+                    self.0[ti] <<= shift
                 }
             }
         }
@@ -110,7 +119,12 @@ impl Shl<udef> for BigInt {
 
 impl Ord for BigInt {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.cmp(&other)
+        // Compare from the most significant end, ignoring leading zero limbs
+        let [l, r] = [&self.0, &other.0].map(|v| {
+            let len = v.len() - v.iter().rev().take_while(|&&x| x == 0).count();
+            &v[..len]
+        });
+        l.len().cmp(&r.len()).then_with(|| l.iter().rev().cmp(r.iter().rev()))
     }
 }
 
@@ -129,10 +143,6 @@ impl PartialEq for BigInt {
             [&self.0, &rhs.0].map(|v| v.iter().rev().skip_while(|&&x| x == 0));
         without_trailing_zero_l.eq(without_trailing_zero_r)
     }
-
-    fn ne(&self, rhs: &Self) -> bool {
-        !self.eq(rhs)
-    }
 }
 
 impl PartialOrd<udef> for BigInt {
@@ -145,10 +155,6 @@ impl PartialEq<udef> for BigInt {
     fn eq(&self, other: &udef) -> bool {
         self == &BigInt::from(*other)
     }
-
-    fn ne(&self, other: &udef) -> bool {
-        self != &BigInt::from(*other)
-    }
 }
 
 impl LowerHex for BigInt {
@@ -192,7 +198,7 @@ impl Binary for BigInt {
             return Ok(());
         }
         for &byte in iter {
-            write!(f, "{byte:0wid$b}", wid = size_of::<udef>() * 8)?;
+            write!(f, "{byte:0wid$b}", wid = udef::BITS as usize)?;
         }
         Ok(())
     }
@@ -204,20 +210,97 @@ impl From<udef> for BigInt {
     }
 }
 
+impl From<u128> for BigInt {
+    fn from(value: u128) -> Self {
+        BigInt::from_le_bytes(&value.to_le_bytes()).trim()
+    }
+}
+
+impl<'a> Sub<&'a Self> for BigInt {
+    type Output = Self;
+    /// Subtract a smaller or equal BigInt
+    fn sub(mut self, rhs: &'a Self) -> Self::Output {
+        let mut borrow = false;
+        let rhs_iter = rhs.0.iter().copied().chain(iter::repeat(0));
+        for (l, r) in self.0.iter_mut().zip(rhs_iter) {
+            (*l, borrow) = l.borrowing_sub(r, borrow);
+        }
+        debug_assert!(!borrow, "attempt to subtract with overflow");
+        self.trim()
+    }
+}
+
+impl BigInt {
+    /// Drop the zero limbs at the most significant end
+    fn trim(mut self) -> Self {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+        self
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|&x| x == 0)
+    }
+
+    /// Raise a single limb to a power
+    pub fn pow(base: udef, exp: usize) -> Self {
+        (0..exp).fold(BigInt(vec![1]), |acc, _| acc * base)
+    }
+
+    /// Divide by a single limb, returning the quotient and the remainder
+    pub fn div_rem_small(mut self, rhs: udef) -> (Self, udef) {
+        let mut rem: udbl = 0;
+        for limb in self.0.iter_mut().rev() {
+            let cur = (rem << udef::BITS) | *limb as udbl;
+            *limb = (cur / rhs as udbl) as udef;
+            rem = cur % rhs as udbl;
+        }
+        (self.trim(), rem as udef)
+    }
+
+    /// Narrow to a u128, if the value fits
+    pub fn to_u128(&self) -> Option<u128> {
+        let limbs = self.clone().trim().0;
+        if limbs.len() > size_of::<u128>() / size_of::<udef>() {
+            return None;
+        }
+        Some(limbs.iter().rev().fold(0, |acc, &x| (acc << udef::BITS) | x as u128))
+    }
+
+    /// Write out the decimal digits, most significant first
+    pub fn to_decimal(&self) -> String {
+        let mut digits: Vec<u8> = Vec::new();
+        let mut cur = self.clone().trim();
+        while !cur.is_zero() {
+            let (quo, rem) = cur.div_rem_small(10);
+            digits.push(b'0' + rem);
+            cur = quo;
+        }
+        if digits.is_empty() {
+            digits.push(b'0');
+        }
+        digits.iter().rev().map(|&d| d as char).collect()
+    }
+}
+
 #[derive(Clone, Debug)]
 /// ALN - Arbitrary Length Number
 /// 
 /// This type is slow and only meant for precision conversions and displaying
+/// 
+/// Holds an exact decimal: `int + frc / 10^dps`
 pub struct ALN {
     sgn: bool,
     int: BigInt,
     frc: BigInt,
+    dps: usize, // Decimal places held by frc
 }
 
 impl ALN {
     /// Create an empty ALN
     pub fn empty() -> Self {
-        Self { sgn: false, int: BigInt(vec![0]), frc: BigInt(vec![0]) }
+        Self { sgn: false, int: BigInt(vec![0]), frc: BigInt(vec![0]), dps: 0 }
     }
 
     /// Rectify and Validate a string for use with ALN
@@ -225,56 +308,164 @@ impl ALN {
         let sgn: bool = raw.starts_with('-');
         let mut has_dp: bool = false; // Has a decimal point / Is a decimal number
         let mut cnt_int: String = Default::default();
-        let mut valid: String = Default::default();
-        if sgn { valid.push('-'); }
+        let mut cnt_frc: String = Default::default();
         for c in raw.chars() {
             if c.is_ascii_digit() {
-                if (cnt_int.len() > 1) | (c != '0') { // Ignore leading zeroes.
-                    cnt_int.push(c); // Push to integer
-                    valid.push(c);
+                if has_dp {
+                    cnt_frc.push(c);
+                } else if !cnt_int.is_empty() | (c != '0') { // Ignore leading zeroes.
+                    cnt_int.push(c);
                 }
             } else if (c == '.') & (!has_dp) { 
                 has_dp = true; 
-                valid.push('.');
             } // Reject any other character
         }
-        while valid.ends_with('0') { // Eliminate trailing zeroes.
-            valid.pop();
+        while cnt_frc.ends_with('0') { // Eliminate trailing zeroes.
+            cnt_frc.pop();
+        }
+        if cnt_int.is_empty() {
+            cnt_int.push('0');
+        }
+        let mut valid: String = Default::default();
+        if sgn & ((cnt_int != "0") | !cnt_frc.is_empty()) { valid.push('-'); }
+        valid.push_str(&cnt_int);
+        if !cnt_frc.is_empty() {
+            valid.push('.');
+            valid.push_str(&cnt_frc);
         }
         valid
     }
+
+    /// Build from a sign and an integer scaled by 10^dps
+    fn from_scaled(sgn: bool, val: BigInt, dps: usize) -> Self {
+        let digits = val.to_decimal();
+        let digits = format!("{:0>width$}", digits, width = dps + 1);
+        let (int, frc) = digits.split_at(digits.len() - dps);
+        let sign = if sgn { "-" } else { "" };
+        ALN::from(format!("{sign}{int}.{frc}"))
+    }
+
+    /// The magnitude scaled by 10^dps, which must be at least self.dps
+    fn scaled(&self, dps: usize) -> BigInt {
+        let mut frc = self.frc.clone();
+        for _ in self.dps..dps {
+            frc = frc * 10;
+        }
+        let mut int = self.int.clone();
+        for _ in 0..dps {
+            int = int * 10;
+        }
+        int + &frc
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.sgn
+    }
+
+    /// Number of decimal places needed to write the value exactly
+    pub fn decimal_places(&self) -> usize {
+        self.dps
+    }
+
+    /// The absolute difference between two values
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        let dps = self.dps.max(other.dps);
+        let (l, r) = (self.scaled(dps), other.scaled(dps));
+        if self.sgn != other.sgn {
+            return ALN::from_scaled(false, l + &r, dps);
+        }
+        if l >= r {
+            ALN::from_scaled(false, l - &r, dps)
+        } else {
+            ALN::from_scaled(false, r - &l, dps)
+        }
+    }
+
+    /// Exact value of a fixed-point magnitude with `frac` fraction bits
+    pub fn from_bits(sgn: bool, mag: u128, frac: u32) -> Self {
+        let int = mag.checked_shr(frac).unwrap_or(0);
+        let rem = if frac >= 128 { mag } else { mag & ((1u128 << frac) - 1) };
+        // rem / 2^frac is exactly rem * 5^frac / 10^frac
+        let frc = BigInt::from(rem);
+        let frc = (0..frac).fold(frc, |acc, _| acc * 5);
+        let mut aln = ALN { sgn, int: BigInt::from(int), frc, dps: frac as usize };
+        aln.normalize();
+        aln
+    }
+
+    /// Round to a fixed-point magnitude with `frac` fraction bits
+    /// 
+    /// Returns the sign, the magnitude and whether the value saturated to `max_pos` or `max_neg`
+    pub fn to_bits(&self, frac: u32, max_pos: u128, max_neg: u128, mode: RoundingMode) -> (bool, u128, bool) {
+        let max = if self.sgn { max_neg } else { max_pos };
+        let int = match self.int.to_u128() {
+            Some(int) if int <= max.checked_shr(frac).unwrap_or(0) => int,
+            _ => return (self.sgn, max, true),
+        };
+        // Long division of the decimal fraction, one bit at a time
+        let den = BigInt::pow(10, self.dps);
+        let mut rem = self.frc.clone();
+        let mut bits: u128 = 0;
+        for _ in 0..frac {
+            rem = rem * 2;
+            bits <<= 1;
+            if rem >= den {
+                rem = rem - &den;
+                bits |= 1;
+            }
+        }
+        let half = (rem.clone() * 2).cmp(&den);
+        let mag = int.checked_shl(frac).unwrap_or(0) | bits;
+        let up = mode.round_up(self.sgn, mag & 1 == 1, half, !rem.is_zero());
+        match mag.checked_add(up as u128) {
+            Some(mag) if mag <= max => (self.sgn, mag, false),
+            _ => (self.sgn, max, true),
+        }
+    }
+
+    /// Compare the rounded result of a conversion against the exact source value
+    pub fn report(&self, result: ALN, frac: u32, saturated: bool) -> RoundingReport {
+        let error = result.abs_diff(self);
+        // Scale the error by 2^frac to express it in units of the last place
+        let ulps = error.scaled(error.dps);
+        let ulps = (0..frac).fold(ulps, |acc, _| acc * 2);
+        let ulps = ALN::from_scaled(false, ulps, error.dps);
+        RoundingReport { direction: result.cmp(self), source: self.clone(), result, error, ulps, saturated }
+    }
+
+    /// Remove trailing zero decimal places and the sign of zero
+    fn normalize(&mut self) {
+        while self.dps > 0 {
+            let (quo, rem) = self.frc.clone().div_rem_small(10);
+            if rem != 0 { break }
+            self.frc = quo;
+            self.dps -= 1;
+        }
+        if self.int.is_zero() & self.frc.is_zero() {
+            self.sgn = false;
+        }
+    }
 }
 
 impl From<String> for ALN {
     fn from(value: String) -> Self {
+        let value = ALN::rectify_string(value);
         let mut aln = Self::empty();
-        let int: String;
-        let frc: String;
-        let dec: bool;
-        if value.contains('.') {
-            let col: Vec<&str> = value.split('.').collect();
-            int = col[0].to_string();
-            frc = col[1].to_string();
-            dec = true;
-        } else {
-            int = value;
-            frc = Default::default();
-            dec = false;
-        }
-        for (_i, c) in int.chars().enumerate() {
+        aln.sgn = value.starts_with('-');
+        let (int, frc) = value.trim_start_matches('-').split_once('.').unwrap_or((&value, ""));
+        for c in int.chars() {
             if let Some(digit) = c.to_digit(10) {
                 aln.int = (aln.int * 10) + &BigInt::from(digit as udef);
             }
         }
-        if dec {
-            for (_i, c) in frc.chars().enumerate() {
-                if let Some(digit) = c.to_digit(10) {
-                    aln.frc = (aln.frc * 10) + &BigInt::from(digit as udef);
-                }
+        // The fraction is an integer over 10^dps, so "0.05" and "0.5" stay distinct
+        for c in frc.chars() {
+            if let Some(digit) = c.to_digit(10) {
+                aln.frc = (aln.frc * 10) + &BigInt::from(digit as udef);
+                aln.dps += 1;
             }
-            // whole sequence bits / (1 << (total bits - decimal place))
         }
-        println!("{:b}.{:b}", aln.int, aln.frc);
+        aln.normalize();
         aln
     }
 }
@@ -283,4 +474,105 @@ impl From<&str> for ALN {
     fn from(value: &str) -> Self {
         ALN::from(value.to_string())
     }
-}
\ No newline at end of file
+}
+
+impl Display for ALN {
+    /// Print the exact decimal value, rounded to nearest (ties to even) when a precision is given
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut int = self.int.to_decimal();
+        let mut frc = format!("{:0>width$}", self.frc.to_decimal(), width = self.dps);
+        if self.dps == 0 {
+            frc.clear();
+        }
+        if let Some(prec) = f.precision() {
+            if prec < frc.len() {
+                let (keep, rest) = frc.split_at(prec);
+                let last_odd = keep.bytes().last().or(int.bytes().last()).is_some_and(|d| d % 2 == 1);
+                let rest = rest.trim_end_matches('0');
+                let up = match rest.as_bytes().first() {
+                    Some(&d) if d > b'5' => true,
+                    Some(&b'5') => rest.len() > 1 || last_odd,
+                    _ => false,
+                };
+                let mut digits: Vec<u8> = int.bytes().chain(keep.bytes()).collect();
+                if up {
+                    let mut carry = true;
+                    for d in digits.iter_mut().rev() {
+                        if *d == b'9' {
+                            *d = b'0';
+                        } else {
+                            *d += 1;
+                            carry = false;
+                            break;
+                        }
+                    }
+                    if carry {
+                        digits.insert(0, b'1');
+                    }
+                }
+                let split = digits.len() - prec;
+                int = String::from_utf8(digits[..split].to_vec()).unwrap_or_default();
+                frc = String::from_utf8(digits[split..].to_vec()).unwrap_or_default();
+            } else {
+                frc = format!("{frc:0<prec$}");
+            }
+        }
+        let out = if frc.is_empty() { int } else { format!("{int}.{frc}") };
+        f.pad_integral(!self.sgn, "", &out)
+    }
+}
+
+impl Ord for ALN {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let dps = self.dps.max(other.dps);
+        match (self.sgn, other.sgn) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => self.scaled(dps).cmp(&other.scaled(dps)),
+            (true, true) => other.scaled(dps).cmp(&self.scaled(dps)),
+        }
+    }
+}
+
+impl PartialOrd for ALN {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for ALN {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ALN { }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Record of how far a conversion from an ALN had to round
+pub struct RoundingReport {
+    /// The exact value that was converted
+    pub source: ALN,
+    /// The exact value of the rounded result
+    pub result: ALN,
+    /// The absolute rounding error
+    pub error: ALN,
+    /// The absolute rounding error in units of the last place of the result
+    pub ulps: ALN,
+    /// Whether the result rounded up (Greater), down (Less) or was exact (Equal)
+    pub direction: Ordering,
+    /// Whether the source was out of range and the result saturated
+    pub saturated: bool,
+}
+
+impl RoundingReport {
+    /// Whether the conversion lost nothing
+    pub fn is_exact(&self) -> bool {
+        self.direction == Ordering::Equal
+    }
+
+    /// Whether the result is correctly rounded to nearest, within half a unit in the last place
+    pub fn within_half_ulp(&self) -> bool {
+        !self.saturated && self.ulps <= ALN::from("0.5")
+    }
+}
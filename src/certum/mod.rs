@@ -1,5 +1,3 @@
-#![allow(unused_imports)]
-
 // Signed Certums
 pub mod certum8;
 pub mod certum16;
@@ -12,208 +10,4 @@ pub mod u_certum8;
 pub mod u_certum16;
 pub mod u_certum32;
 pub mod u_certum64;
-pub mod u_certum128;
-
-use {
-    std::cmp::{Eq, PartialEq, Ordering},
-    std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Neg},
-    crate::{
-        negate, 
-        from_direct, 
-        from_right_shift_signed, 
-        from_right_shift, 
-        from_left_shift_signed, 
-        from_left_shift, 
-        comparison_solo_signed,
-        comparison_solo_unsigned,
-        equivalent_solo, 
-        equivalent_bits,
-        sub_same,
-        add_same, 
-        mul_same_signed,
-        mul_same_signed_128,
-        mul_same_unsigned,
-        mul_same_unsigned_128,
-        from_u256,
-        float_casts,
-        float_convert_sc, 
-        float_convert_uc,
-        lossy_float,
-    },
-    super::{c8, uc8, c16, uc16, c32, uc32, c64, uc64, c128, uc128, u256},
-    super::utils::{f64_split, QuickLog},
-};
-
-// Float Casting
-
-float_convert_sc!(c8,  u8,  i8,  8,   2, 0x7F);
-float_convert_sc!(c16, u16, i16, 16,  3, 0x7FFF);
-float_convert_sc!(c32, u32, i32, 32,  4, 0x7FFFFFFF);
-float_convert_sc!(c64, u64, i64, 64,  5, 0x7FFFFFFFFFFFFFFF);
-lossy_float!(c128, c64, 1);
-float_convert_uc!(uc8,  u8,  8,  2, 0x7F);
-float_convert_uc!(uc16, u16, 16, 3, 0x7FFF);
-float_convert_uc!(uc32, u32, 32, 4, 0x7FFFFFFF);
-float_convert_uc!(uc64, u64, 64, 5, 0x7FFFFFFFFFFFFFFF);
-lossy_float!(uc128, uc64, 1);
-float_casts!(c8,    u8);
-float_casts!(c16,   u16);
-float_casts!(c32,   u32);
-float_casts!(c64,   u64);
-float_casts!(c128,  u128);
-float_casts!(uc8,   u8);
-float_casts!(uc16,  u16);
-float_casts!(uc32,  u32);
-float_casts!(uc64,  u64);
-float_casts!(uc128, u128);
-
-// Negation
-
-negate!(c8);
-negate!(c16);
-negate!(c32);
-negate!(c64);
-negate!(c128);
-negate!(uc8);
-negate!(uc16);
-negate!(uc32);
-negate!(uc64);
-negate!(uc128);
-
-// Type Conversion
-
-from_direct!(c8,   uc8);
-from_direct!(c16,  uc16);
-from_direct!(c32,  uc32);
-from_direct!(c64,  uc64);
-from_direct!(c128,  uc128);
-from_direct!(uc8,  c8);
-from_direct!(uc16, c16);
-from_direct!(uc32, c32);
-from_direct!(uc64, c64);
-from_direct!(uc128, c128);
-
-// Smaller to Bigger
-from_left_shift_signed!(c8,  c16,  u16,  16,  8,  1);
-from_left_shift_signed!(c8,  c32,  u32,  32,  8,  2);
-from_left_shift_signed!(c8,  c64,  u64,  64,  8,  3);
-from_left_shift_signed!(c8,  c128, u128, 128, 8,  4);
-from_left_shift_signed!(c16, c32,  u32,  32,  16, 1);
-from_left_shift_signed!(c16, c64,  u64,  64,  16, 2);
-from_left_shift_signed!(c16, c128, u128, 128, 16, 3);
-from_left_shift_signed!(c32, c64,  u64,  64,  32, 1);
-from_left_shift_signed!(c32, c128, u128, 128, 32, 2);
-from_left_shift_signed!(c64, c128, u128, 128, 64, 1);
-
-// Bigger to Smaller
-from_right_shift_signed!(c128, c64, u64, 64, 128, 1);
-from_right_shift_signed!(c128, c32, u32, 32, 128, 2);
-from_right_shift_signed!(c128, c16, u16, 16, 128, 3);
-from_right_shift_signed!(c128, c8,  u8,  8,  128, 4);
-from_right_shift_signed!(c64,  c32, u32, 32, 64,  1);
-from_right_shift_signed!(c64,  c16, u16, 16, 64,  2);
-from_right_shift_signed!(c64,  c8,  u8,  8,  64,  3);
-from_right_shift_signed!(c32,  c16, u16, 16, 32,  1);
-from_right_shift_signed!(c32,  c8,  u8,  8,  32,  2);
-from_right_shift_signed!(c16,  c8,  u8,  8,  16,  1);
-
-// Smaller to Bigger
-from_left_shift!(uc8,  uc16,  u16,  8, 1);
-from_left_shift!(uc8,  uc32,  u32,  8, 2);
-from_left_shift!(uc8,  uc64,  u64,  8, 3);
-from_left_shift!(uc8,  uc128, u128, 8, 4);
-from_left_shift!(uc16, uc32,  u32,  16, 1);
-from_left_shift!(uc16, uc64,  u64,  16, 2);
-from_left_shift!(uc16, uc128, u128, 16, 3);
-from_left_shift!(uc32, uc64,  u64,  32, 1);
-from_left_shift!(uc32, uc128, u128, 32, 2);
-from_left_shift!(uc64, uc128, u128, 64, 1);
-
-// Bigger to Smaller
-from_right_shift!(uc128, uc64, u64, 64, 1);
-from_right_shift!(uc128, uc32, u32, 32, 2);
-from_right_shift!(uc128, uc16, u16, 16, 3);
-from_right_shift!(uc128, uc8,  u8,  8, 4);
-from_right_shift!(uc64,  uc32, u32, 32, 1);
-from_right_shift!(uc64,  uc16, u16, 16, 2);
-from_right_shift!(uc64,  uc8,  u8,  8, 3);
-from_right_shift!(uc32,  uc16, u16, 16, 1);
-from_right_shift!(uc32,  uc8,  u8,  8, 2);
-from_right_shift!(uc16,  uc8,  u8,  8, 1);
-
-// Checks and Comparisons
-
-comparison_solo_signed!(c8,   i8);
-comparison_solo_signed!(c16,  i16);
-comparison_solo_signed!(c32,  i32);
-comparison_solo_signed!(c64,  i64);
-comparison_solo_signed!(c128, i128);
-
-comparison_solo_unsigned!(uc8);
-comparison_solo_unsigned!(uc16);
-comparison_solo_unsigned!(uc32);
-comparison_solo_unsigned!(uc64);
-comparison_solo_unsigned!(uc128);
-
-equivalent_solo!(c8);
-equivalent_solo!(c16);
-equivalent_solo!(c32);
-equivalent_solo!(c64);
-equivalent_solo!(c128);
-
-equivalent_solo!(uc8);
-equivalent_solo!(uc16);
-equivalent_solo!(uc32);
-equivalent_solo!(uc64);
-equivalent_solo!(uc128);
-
-equivalent_bits!(c8, u8);
-equivalent_bits!(c16, u16);
-equivalent_bits!(c32, u32);
-equivalent_bits!(c64, u64);
-equivalent_bits!(c128, u128);
-
-equivalent_bits!(uc8, u8);
-equivalent_bits!(uc16, u16);
-equivalent_bits!(uc32, u32);
-equivalent_bits!(uc64, u64);
-equivalent_bits!(uc128, u128);
-
-// Algebra
-
-add_same!(c8,    u8,   i8);
-add_same!(c16,   u16,  i16);
-add_same!(c32,   u32,  i32);
-add_same!(c64,   u64,  i64);
-add_same!(c128,  u128, i128);
-add_same!(uc8,   u8,   u8);
-add_same!(uc16,  u16,  u16);
-add_same!(uc32,  u32,  u32);
-add_same!(uc64,  u64,  u64);
-add_same!(uc128, u128, u128);
-
-sub_same!(c8,    u8,   i8);
-sub_same!(c16,   u16,  i16);
-sub_same!(c32,   u32,  i32);
-sub_same!(c64,   u64,  i64);
-sub_same!(c128,  u128, i128);
-sub_same!(uc8,   u8,   u8);
-sub_same!(uc16,  u16,  u16);
-sub_same!(uc32,  u32,  u32);
-sub_same!(uc64,  u64,  u64);
-sub_same!(uc128, u128, u128);
-
-// Multiplication for u128 types is handled manually- Tuple quire needed
-
-mul_same_signed!(c8, u8, u16);
-mul_same_signed!(c16, u16, u32);
-mul_same_signed!(c32, u32, u64);
-mul_same_signed!(c64, u64, u128);
-from_u256!(c128);
-mul_same_signed_128!(c128, u128, u256);
-mul_same_unsigned!(uc8, u8, u16);
-mul_same_unsigned!(uc16, u16, u32);
-mul_same_unsigned!(uc32, u32, u64);
-mul_same_unsigned!(uc64, u64, u128);
-from_u256!(uc128);
-mul_same_unsigned_128!(uc128, u128, u256);
\ No newline at end of file
+pub mod u_certum128;
\ No newline at end of file
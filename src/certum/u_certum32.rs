@@ -1,38 +1,16 @@
-#![allow(dead_code)]
+use crate::fixed::unsigned::UFixed;
 
-#[derive(Copy, Clone, Debug)]
 #[expect(non_camel_case_types)]
 /// Define a generic 32-bit Unsigned Certum
-pub struct uc32 {
-    /// The raw bits of the certum
-    /// 
-    /// 4 Integer bits, 28 Fraction bits
-    pub bits: u32
-}
+/// 
+/// 4 Integer bits, 28 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 15.9999999962747097015380859375
+pub type uc32 = UFixed<u32, 28>;
 
 impl uc32 {
-    /// Bits before the decimal point
-    pub const DEC: u32 = 5;
-    /// Bits after the decimal point
-    pub const FRC: u32 = 28;
-    /// Literal bits after the decimal point. 2 ^ FRC
-    pub const FRCPOW: u32 = 268435456;
-    /// Minimum value for a 32-bit Unsigned Certum.
-    /// 
-    /// Decimal: 0
-    pub const MIN: Self = Self { bits: 0 };
-    /// Maximum value for a 32-bit Unsigned Certum.
-    /// 
-    /// Decimal: 15.9999999962747097015380859375
-    pub const MAX: Self = Self { bits: 0xFFFFFFFF };
-    /// Minimum value as a 64-bit Float
-    pub const MINF: f64 = 0f64;
-    /// Maximum value as a 64-bit Float
-    pub const MAXF: f64 = 15.99999999627471f64;
-    /// Grain - Smallest possible absolute quantity of this type
-    pub const GRN: Self = Self { bits: 0b1 };
-    /// One - The certum equivalent of integer 1
-    pub const ONE: u32 = 268435457;
     /// Archimedes' Constant - π
     /// 
     /// Decimal: 3.1415926516056060791015625
@@ -49,44 +27,4 @@ impl uc32 {
     /// Digits of Accuracy: 9
     /// 
     pub const E: Self = Self { bits: 0x2B7E1516 };
-
-    /// Get a new certum from raw bits
-    pub fn of(bits: u32) -> Self {
-        Self { bits }
-    }
-    
-    /// Return the binary components of the current certum
-    /// 
-    /// (Integer Component, Fraction Component)
-    pub fn components(&self) -> (u32, u32) {
-        // Order ints smallest component as LSB
-        // 32 bits - 4 int bits = 28 bit shifts
-        let int = self.bits >> 28;
-        // Order fraction's largest component as MSB
-        // 32 bits - 28 frc bits = 4 bit shifts
-        let frc = self.bits << 4;
-        (int, frc)
-    }
-
-    /// Clamp a u64 and round to a u32 properly.
-    /// 
-    /// Right-shift MSB to (64 - 33), carry case with + 1, right-shift MSB to make 32 bits. Clamp to u32
-    pub fn u64_round(val: u64) -> u32 {
-        ((val + 0x80000000) >> 32) as u32
-    }
-
-    /// Print line to console with a name and bits
-    pub fn log_bits(&self) {
-        println!("0b{:032b}", self.bits);
-    }
-
-    /// Print line to console with a name and hexadecimal bits
-    pub fn log_hex(&self) {
-        println!("0x{:08X}", self.bits);
-    }
-
-    /// Print line to console with a name and float value
-    pub fn log_value(&self) {
-        println!("{:.32}", f64::from(self));
-    }
 }
\ No newline at end of file
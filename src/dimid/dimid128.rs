@@ -0,0 +1,30 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 128-bit Signed Dimid
+/// 
+/// 1 Sign bit, 63 Integer bits, 64 Fraction bits
+/// 
+/// Minimum Decimal: -9223372036854775808
+/// 
+/// Maximum Decimal: 9223372036854775807.9999999999999999999457898913757247782996273599565029144287109375
+pub type d128 = Fixed<u128, 64>;
+
+impl d128 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.1415926535897932384585988507819109827323700301349163055419921875
+    /// Exact:   3.1415926535897932384
+    /// 
+    /// Digits of Accuracy: 20
+    /// 
+    pub const PI: Self = Self { bits: 0x3243F6A8885A308D3 };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.718281828459045235319747890745389895528205670416355133056640625
+    /// Exact:   2.7182818284590452353
+    /// 
+    /// Digits of Accuracy: 20
+    /// 
+    pub const E: Self = Self { bits: 0x2B7E151628AED2A6A };
+}
\ No newline at end of file
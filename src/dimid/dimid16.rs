@@ -0,0 +1,30 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 16-bit Signed Dimid
+/// 
+/// 1 Sign bit, 7 Integer bits, 8 Fraction bits
+/// 
+/// Minimum Decimal: -128
+/// 
+/// Maximum Decimal: 127.99609375
+pub type d16 = Fixed<u16, 8>;
+
+impl d16 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.140625
+    /// Exact:   3.14
+    /// 
+    /// Digits of Accuracy: 3
+    /// 
+    pub const PI: Self = Self { bits: 0x324 };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.71484375
+    /// Exact:   2.71
+    /// 
+    /// Digits of Accuracy: 3
+    /// 
+    pub const E: Self = Self { bits: 0x2B7 };
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 32-bit Signed Dimid
+/// 
+/// 1 Sign bit, 15 Integer bits, 16 Fraction bits
+/// 
+/// Minimum Decimal: -32768
+/// 
+/// Maximum Decimal: 32767.9999847412109375
+pub type d32 = Fixed<u32, 16>;
+
+impl d32 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.1415863037109375
+    /// Exact:   3.1415
+    /// 
+    /// Digits of Accuracy: 5
+    /// 
+    pub const PI: Self = Self { bits: 0x3243F };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.7182769775390625
+    /// Exact:   2.7182
+    /// 
+    /// Digits of Accuracy: 5
+    /// 
+    pub const E: Self = Self { bits: 0x2B7E1 };
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 64-bit Signed Dimid
+/// 
+/// 1 Sign bit, 31 Integer bits, 32 Fraction bits
+/// 
+/// Minimum Decimal: -2147483648
+/// 
+/// Maximum Decimal: 2147483647.99999999976716935634613037109375
+pub type d64 = Fixed<u64, 32>;
+
+impl d64 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.14159265346825122833251953125
+    /// Exact:   3.141592653
+    /// 
+    /// Digits of Accuracy: 10
+    /// 
+    pub const PI: Self = Self { bits: 0x3243F6A88 };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.7182818283326923847198486328125
+    /// Exact:   2.718281828
+    /// 
+    /// Digits of Accuracy: 10
+    /// 
+    pub const E: Self = Self { bits: 0x2B7E15162 };
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 8-bit Signed Dimid
+/// 
+/// 1 Sign bit, 3 Integer bits, 4 Fraction bits
+/// 
+/// Minimum Decimal: -8
+/// 
+/// Maximum Decimal: 7.9375
+pub type d8 = Fixed<u8, 4>;
+
+impl d8 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.125
+    /// Exact:   3.1
+    /// 
+    /// Digits of Accuracy: 2
+    /// 
+    pub const PI: Self = Self { bits: 0x32 };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.6875
+    /// Exact:   2.
+    /// 
+    /// Digits of Accuracy: 1
+    /// 
+    pub const E: Self = Self { bits: 0x2B };
+}
\ No newline at end of file
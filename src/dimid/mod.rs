@@ -0,0 +1,13 @@
+// Signed Dimids
+pub mod dimid8;
+pub mod dimid16;
+pub mod dimid32;
+pub mod dimid64;
+pub mod dimid128;
+
+// Unsigned Dimids
+pub mod u_dimid8;
+pub mod u_dimid16;
+pub mod u_dimid32;
+pub mod u_dimid64;
+pub mod u_dimid128;
\ No newline at end of file
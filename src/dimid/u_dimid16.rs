@@ -0,0 +1,30 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 16-bit Unsigned Dimid
+/// 
+/// 8 Integer bits, 8 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 255.99609375
+pub type ud16 = UFixed<u16, 8>;
+
+impl ud16 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.140625
+    /// Exact:   3.14
+    /// 
+    /// Digits of Accuracy: 3
+    /// 
+    pub const PI: Self = Self { bits: 0x324 };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.71484375
+    /// Exact:   2.71
+    /// 
+    /// Digits of Accuracy: 3
+    /// 
+    pub const E: Self = Self { bits: 0x2B7 };
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 32-bit Unsigned Dimid
+/// 
+/// 16 Integer bits, 16 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 65535.9999847412109375
+pub type ud32 = UFixed<u32, 16>;
+
+impl ud32 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.1415863037109375
+    /// Exact:   3.1415
+    /// 
+    /// Digits of Accuracy: 5
+    /// 
+    pub const PI: Self = Self { bits: 0x3243F };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.7182769775390625
+    /// Exact:   2.7182
+    /// 
+    /// Digits of Accuracy: 5
+    /// 
+    pub const E: Self = Self { bits: 0x2B7E1 };
+}
\ No newline at end of file
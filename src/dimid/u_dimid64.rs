@@ -0,0 +1,30 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 64-bit Unsigned Dimid
+/// 
+/// 32 Integer bits, 32 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 4294967295.99999999976716935634613037109375
+pub type ud64 = UFixed<u64, 32>;
+
+impl ud64 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.14159265346825122833251953125
+    /// Exact:   3.141592653
+    /// 
+    /// Digits of Accuracy: 10
+    /// 
+    pub const PI: Self = Self { bits: 0x3243F6A88 };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.7182818283326923847198486328125
+    /// Exact:   2.718281828
+    /// 
+    /// Digits of Accuracy: 10
+    /// 
+    pub const E: Self = Self { bits: 0x2B7E15162 };
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 8-bit Unsigned Dimid
+/// 
+/// 4 Integer bits, 4 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 15.9375
+pub type ud8 = UFixed<u8, 4>;
+
+impl ud8 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.125
+    /// Exact:   3.1
+    /// 
+    /// Digits of Accuracy: 2
+    /// 
+    pub const PI: Self = Self { bits: 0x32 };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.6875
+    /// Exact:   2.
+    /// 
+    /// Digits of Accuracy: 1
+    /// 
+    pub const E: Self = Self { bits: 0x2B };
+}
\ No newline at end of file
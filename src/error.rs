@@ -0,0 +1,57 @@
+use std::{error::Error, fmt::{self, Display, Formatter}};
+use crate::fixed::decimal::{ParseCertumError, ParseErrorKind};
+
+/// Errors reported by the crate: failed conversions, failed parsing and raised status flags
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CertumError {
+    /// The value is above MAX or below MIN, or infinite
+    Overflow,
+    /// The value is not zero, but rounds to zero
+    Underflow,
+    /// The value is not a number
+    NaN,
+    /// The value is in range but cannot be represented exactly. Only reported by strict conversions and status flags
+    PrecisionLoss,
+    /// A value was divided by zero. Only reported by status flags
+    DivideByZero,
+    /// A decimal string could not be parsed
+    Parse(ParseErrorKind),
+}
+
+impl Display for CertumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Overflow => "value out of range of the target type",
+            Self::Underflow => "nonzero value rounds to zero in the target type",
+            Self::NaN => "cannot convert NaN to a fixed-point value",
+            Self::PrecisionLoss => "value cannot be represented exactly in the target type",
+            Self::DivideByZero => "attempt to divide by zero",
+            Self::Parse(kind) => return ParseCertumError::new(*kind).fmt(f),
+        })
+    }
+}
+
+impl Error for CertumError {}
+
+impl From<ParseCertumError> for CertumError {
+    fn from(err: ParseCertumError) -> Self {
+        Self::Parse(*err.kind())
+    }
+}
+
+/// Conversion that reports out-of-range values instead of saturating
+///
+/// `TryFrom` is implemented through this for integers. Floats, certums of other widths and each storage's own
+/// unsigned integer already convert through the saturating `From`, which rules out `TryFrom` for them,
+/// so they use this trait instead
+pub trait FromChecked<T>: Sized {
+    /// Convert `value`, rounding to nearest (ties to even).
+    ///
+    /// Fails with Overflow, Underflow or NaN
+    fn from_checked(value: T) -> Result<Self, CertumError>;
+
+    /// Convert `value` exactly.
+    ///
+    /// Fails like `from_checked`, and with PrecisionLoss when any bits would be rounded away
+    fn from_strict(value: T) -> Result<Self, CertumError>;
+}
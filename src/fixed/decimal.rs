@@ -0,0 +1,179 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+use crate::flags::raise_rounded;
+
+/// Reason a decimal string could not be parsed into a fixed-point value
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The string was empty, or held only a sign or decimal point
+    Empty,
+    /// The string held a character that is not a digit, sign or single decimal point
+    InvalidDigit,
+    /// The value rounds to something above the maximum of the type
+    PosOverflow,
+    /// The value rounds to something below the minimum of the type
+    NegOverflow,
+}
+
+/// Error returned when parsing a fixed-point value from a string fails
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseCertumError {
+    kind: ParseErrorKind,
+}
+
+impl ParseCertumError {
+    pub(crate) const fn new(kind: ParseErrorKind) -> Self {
+        Self { kind }
+    }
+
+    /// Get the reason parsing failed
+    pub const fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ParseCertumError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            ParseErrorKind::Empty => "cannot parse fixed-point value from empty string",
+            ParseErrorKind::InvalidDigit => "invalid digit found in string",
+            ParseErrorKind::PosOverflow => "number too large to fit in target type",
+            ParseErrorKind::NegOverflow => "number too small to fit in target type",
+        })
+    }
+}
+
+impl Error for ParseCertumError {}
+
+/// Multiply a fraction of `frac` bits by ten, returning the integer digit that carries out
+fn next_digit(val: u128, frac: u32) -> (u8, u128) {
+    let (lo, hi) = val.carrying_mul(10, 0);
+    if frac == 128 {
+        return (hi as u8, lo);
+    }
+    let digit = (lo >> frac) | hi.checked_shl(128 - frac).unwrap_or(0);
+    (digit as u8, lo & ((1u128 << frac) - 1))
+}
+
+/// Write the exact decimal expansion of a fixed-point magnitude with `frac` fraction bits
+///
+/// Every binary fraction has a finite decimal expansion, so without a precision all
+/// significant digits are printed. With a precision the digits are rounded to nearest, ties to even.
+pub fn write_decimal(f: &mut Formatter<'_>, neg: bool, mag: u128, frac: u32) -> fmt::Result {
+    let mut int = mag.checked_shr(frac).unwrap_or(0);
+    let mut rem = if frac >= 128 { mag } else { mag & ((1u128 << frac) - 1) };
+    let mut digits: Vec<u8> = Vec::new();
+    match f.precision() {
+        Some(prec) => {
+            for _ in 0..prec {
+                let (digit, next) = next_digit(rem, frac);
+                digits.push(digit);
+                rem = next;
+            }
+            // Compare the leftover fraction against one half
+            let half = if frac == 0 { 0 } else { 1u128 << (frac - 1) };
+            let last_odd = match digits.last() {
+                Some(digit) => digit % 2 == 1,
+                None => int % 2 == 1,
+            };
+            if frac > 0 && (rem > half || (rem == half && last_odd)) {
+                let mut carry = true;
+                for digit in digits.iter_mut().rev() {
+                    if *digit == 9 {
+                        *digit = 0;
+                    } else {
+                        *digit += 1;
+                        carry = false;
+                        break;
+                    }
+                }
+                if carry {
+                    int += 1;
+                }
+            }
+        }
+        None => {
+            while rem != 0 {
+                let (digit, next) = next_digit(rem, frac);
+                digits.push(digit);
+                rem = next;
+            }
+        }
+    }
+    let mut out = int.to_string();
+    if !digits.is_empty() {
+        out.push('.');
+        out.extend(digits.iter().map(|d| (b'0' + d) as char));
+    }
+    f.pad_integral(!neg, "", &out)
+}
+
+/// Parse a decimal string into a sign and the nearest fixed-point magnitude with `frac` fraction bits
+///
+/// Ties round to even, raising the inexact flag. Magnitudes above `max_pos` (or `max_neg` for negative strings) are rejected.
+pub fn parse_decimal(src: &str, frac: u32, max_pos: u128, max_neg: u128) -> Result<(bool, u128), ParseCertumError> {
+    let (neg, body) = match src.as_bytes().first() {
+        Some(b'-') => (true, &src[1..]),
+        Some(b'+') => (false, &src[1..]),
+        _ => (false, src),
+    };
+    let (int_str, frc_str) = match body.split_once('.') {
+        Some((int, frc)) => (int, frc),
+        None => (body, ""),
+    };
+    if int_str.is_empty() && frc_str.is_empty() {
+        return Err(ParseCertumError::new(ParseErrorKind::Empty));
+    }
+    if !int_str.bytes().chain(frc_str.bytes()).all(|c| c.is_ascii_digit()) {
+        return Err(ParseCertumError::new(ParseErrorKind::InvalidDigit));
+    }
+    let overflow = ParseCertumError::new(if neg { ParseErrorKind::NegOverflow } else { ParseErrorKind::PosOverflow });
+    let max = if neg { max_neg } else { max_pos };
+    // Integer part, bounded by the largest whole number the type can hold
+    let max_int = max.checked_shr(frac).unwrap_or(0);
+    let mut int: u128 = 0;
+    for c in int_str.bytes() {
+        int = int.checked_mul(10)
+            .and_then(|v| v.checked_add((c - b'0') as u128))
+            .filter(|&v| v <= max_int)
+            .ok_or(overflow)?;
+    }
+    // Fraction part, doubled one bit at a time in decimal so no digit is lost
+    let mut dec: Vec<u8> = frc_str.bytes().map(|c| c - b'0').collect();
+    while dec.last() == Some(&0) {
+        dec.pop();
+    }
+    let mut bits: u128 = 0;
+    for _ in 0..frac {
+        let mut carry = 0;
+        for digit in dec.iter_mut().rev() {
+            let doubled = *digit * 2 + carry;
+            *digit = doubled % 10;
+            carry = doubled / 10;
+        }
+        bits = (bits << 1) | carry as u128;
+        while dec.last() == Some(&0) {
+            dec.pop();
+        }
+    }
+    // Round the remaining fraction to nearest, ties to even
+    let round_up = match dec.first() {
+        Some(&d) if d > 5 => true,
+        Some(&5) => dec.len() > 1 || bits & 1 == 1,
+        _ => false,
+    };
+    let mut mag = int.checked_shl(frac).unwrap_or(0) | bits;
+    if round_up {
+        mag = mag.checked_add(1).ok_or(overflow)?;
+    }
+    // Unsigned types pass a negative bound of zero, so only negative zero is accepted
+    if mag > max {
+        return Err(overflow);
+    }
+    if !dec.is_empty() {
+        raise_rounded(mag == 0);
+    }
+    Ok((neg, mag))
+}
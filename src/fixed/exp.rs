@@ -0,0 +1,144 @@
+use crate::quire::quire256::u256;
+use super::wide::{self, FRAC, ONE};
+
+/// ln(2) at working precision
+const LN_2: u256 = u256 { bits: [0xB17217F7D1CF79AB, 0xC9E3B39803F2F6AF40F343267298B62D] };
+/// ln(10) at working precision
+const LN_10: u256 = u256 { bits: [0x24D763776AAA2B05B, 0xA95B58AE0B4C28A38A3FB3E76977E43A] };
+/// Working values at or above 2^8 overflow every exponential of every layout
+const EXP_LIMIT: u256 = u256 { bits: [1 << 72, 0] };
+/// A power of two beyond the range of every layout, used for saturated exponentials
+const EXP_HUGE: i64 = 1 << 20;
+
+/// The sum of two signed working values
+fn add(lhs: (bool, u256), rhs: (bool, u256)) -> (bool, u256) {
+    let (neg, val) = if lhs.0 == rhs.0 {
+        (lhs.0, lhs.1 + rhs.1)
+    } else if lhs.1 >= rhs.1 {
+        (lhs.0, lhs.1 - rhs.1)
+    } else {
+        (rhs.0, rhs.1 - lhs.1)
+    };
+    (neg && val != u256::MIN, val)
+}
+
+/// The product of two working values as a mantissa and a power of two, with mantissas in [1, 2)
+fn mul_norm(lhs: (u256, i64), rhs: (u256, i64)) -> (u256, i64) {
+    let prod = wide::mul(lhs.0, rhs.0);
+    if prod >= ONE << 1 { (prod >> 1, lhs.1 + rhs.1 + 1) } else { (prod, lhs.1 + rhs.1) }
+}
+
+/// A nonzero magnitude with `frac` fraction bits as a mantissa in [1, 2) and a power of two
+fn normalize(mag: u128, frac: u32) -> (u256, i64) {
+    let top = mag.ilog2();
+    (u256::from(mag) << (FRAC - top) as u128, top as i64 - frac as i64)
+}
+
+/// e^r of a working value in [0, 1) from its Taylor series
+fn exp_series(val: u256) -> u256 {
+    let (mut sum, mut term) = (ONE, ONE);
+    let mut k = 1u128;
+    loop {
+        term = wide::mul(term, val) / k;
+        if term == u256::MIN { break; }
+        sum += term;
+        k += 1;
+    }
+    sum
+}
+
+/// atanh(s) of a working value in [0, 0.2] from its Taylor series
+fn atanh_series(val: u256) -> u256 {
+    let square = wide::mul(val, val);
+    let (mut sum, mut term) = (val, val);
+    let mut k = 1u128;
+    loop {
+        term = wide::mul(term, square);
+        let part = term / (2 * k + 1);
+        if part == u256::MIN { break; }
+        sum += part;
+        k += 1;
+    }
+    sum
+}
+
+/// e^x of a signed working value, as a mantissa in (0.5, 2) and a power of two
+pub fn exp(neg: bool, val: u256) -> (u256, i64) {
+    if val >= EXP_LIMIT { return (ONE, if neg { -EXP_HUGE } else { EXP_HUGE }); }
+    // x = k ln 2 + r with |r| <= ln 2 / 2
+    let k = u128::from((wide::div(val, LN_2) + (ONE >> 1)) >> FRAC as u128);
+    let whole = LN_2 * k;
+    let (rneg, rest) = if val >= whole { (false, val - whole) } else { (true, whole - val) };
+    let mant = exp_series(rest);
+    let mant = if rneg != neg { wide::div(ONE, mant) } else { mant };
+    (mant, if neg { -(k as i64) } else { k as i64 })
+}
+
+/// 2^x of a signed working value, as a mantissa in (0.5, 2) and a power of two
+///
+/// Exact when x is an integer
+pub fn exp2(neg: bool, val: u256) -> (u256, i64) {
+    if val >= EXP_LIMIT { return (ONE, if neg { -EXP_HUGE } else { EXP_HUGE }); }
+    let k = u128::from(val >> FRAC as u128) as i64;
+    let mant = exp_series(wide::mul(val & (ONE - 1), LN_2));
+    if neg { (wide::div(ONE, mant), -k) } else { (mant, k) }
+}
+
+/// A positive magnitude with `frac` fraction bits as 2^e * y with y in (0.7, 1.5), returning e and the signed ln(y)
+fn ln_parts(mag: u128, frac: u32) -> (i64, (bool, u256)) {
+    let (val, exp) = normalize(mag, frac);
+    // Center the mantissa on one, so that the series argument stays below 0.18
+    let (val, exp) = if wide::mul(val, val) > ONE << 1 { (val >> 1, exp + 1) } else { (val, exp) };
+    // ln(y) = 2 atanh((y - 1) / (y + 1))
+    let (neg, num) = if val >= ONE { (false, val - ONE) } else { (true, ONE - val) };
+    (exp, (neg, atanh_series(wide::div(num, val + ONE)) << 1))
+}
+
+/// Natural logarithm of a positive magnitude with `frac` fraction bits, as a signed working value
+pub fn ln(mag: u128, frac: u32) -> (bool, u256) {
+    let (exp, rest) = ln_parts(mag, frac);
+    add((exp < 0, LN_2 * exp.unsigned_abs() as u128), rest)
+}
+
+/// Base 2 logarithm of a positive magnitude with `frac` fraction bits, as a signed working value
+///
+/// Exact for powers of two
+pub fn log2(mag: u128, frac: u32) -> (bool, u256) {
+    let (exp, (neg, rest)) = ln_parts(mag, frac);
+    add((exp < 0, ONE * exp.unsigned_abs() as u128), (neg, wide::div(rest, LN_2)))
+}
+
+/// Base 10 logarithm of a positive magnitude with `frac` fraction bits, as a signed working value
+pub fn log10(mag: u128, frac: u32) -> (bool, u256) {
+    let (neg, val) = ln(mag, frac);
+    (neg, wide::div(val, LN_10))
+}
+
+/// `mag^n` of a nonzero magnitude with `frac` fraction bits, as a mantissa and a power of two
+///
+/// Uses repeated squaring, so the relative error stays within about 2^-185 for every exponent
+pub fn powi(mag: u128, frac: u32, n: i32) -> (u256, i64) {
+    let mut base = normalize(mag, frac);
+    let mut acc = (ONE, 0);
+    let mut left = n.unsigned_abs();
+    while left > 0 {
+        if left & 1 == 1 { acc = mul_norm(acc, base); }
+        base = mul_norm(base, base);
+        left >>= 1;
+    }
+    if n < 0 { (wide::div(ONE, acc.0), -acc.1) } else { acc }
+}
+
+/// `mag^y` of a positive magnitude and a signed exponent, both with `frac` fraction bits, as a mantissa and a power of two
+pub fn powf(mag: u128, yneg: bool, ymag: u128, frac: u32) -> (u256, i64) {
+    // x^y = 2^(y log2(x))
+    let (lneg, log) = log2(mag, frac);
+    let (lo, hi) = log.widening_mul(u256::from(ymag));
+    // The product has FRAC + frac fraction bits, and every exponent of 2^8 or more saturates
+    let top = FRAC + frac + 8;
+    let big = if top >= 256 { hi >> (top - 256) as u128 != u256::MIN } else { hi != u256::MIN || lo >> top as u128 != u256::MIN };
+    let neg = lneg != yneg;
+    if big { return (ONE, if neg { -EXP_HUGE } else { EXP_HUGE }); }
+    let prod = if frac == 0 { lo } else { (lo >> frac as u128) | (hi << (256 - frac) as u128) };
+    exp2(neg, prod)
+}
@@ -0,0 +1,629 @@
+#![allow(unused_imports)]
+
+// Generic Layouts
+pub mod signed;
+pub mod unsigned;
+pub mod decimal;
+pub mod roots;
+pub mod wide;
+pub mod trig;
+pub mod exp;
+pub mod point;
+
+use {
+    std::cmp::{Eq, PartialEq, Ordering},
+    std::fmt::{self, Display, Formatter},
+    std::str::FromStr,
+    std::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Rem, RemAssign, Neg, Shl, ShlAssign, Shr, ShrAssign},
+    crate::{
+        layout_signed,
+        layout_unsigned,
+        negate, 
+        from_direct, 
+        from_storage_signed, 
+        from_storage, 
+        comparison_solo_signed,
+        comparison_solo_unsigned,
+        equivalent_solo, 
+        equivalent_bits,
+        equivalent_other,
+        mixed_ops,
+        sub_same,
+        add_same, 
+        arith_signed,
+        arith_unsigned,
+        mul_same_signed,
+        mul_same_signed_128,
+        mul_same_unsigned,
+        mul_same_unsigned_128,
+        div_same_signed,
+        div_same_signed_128,
+        div_same_unsigned,
+        div_same_unsigned_128,
+        rem_same,
+        int_ops_signed,
+        int_ops_unsigned,
+        roots_signed,
+        roots_unsigned,
+        trig_signed,
+        exp_signed,
+        exp_unsigned,
+        decompose_signed,
+        decompose_unsigned,
+        ulps_signed,
+        ulps_unsigned,
+        from_u256,
+        float_casts,
+        float_casts_ieee,
+        from_checked_float,
+        try_from_int,
+        from_checked_layouts,
+        num_traits_common,
+        num_traits_signed,
+        num_traits_unsigned,
+        fixed_point,
+        float_scaled_signed, 
+        float_scaled_unsigned,
+        decimal_signed,
+        decimal_unsigned,
+        aln_signed,
+        aln_unsigned,
+        quire_signed,
+        quire_unsigned,
+    },
+    super::{u256, i256},
+    super::quire::accumulator::Quire,
+    super::allen::allen::{ALN, RoundingReport},
+    super::rounding::{RoundingMode, FromRound},
+    super::error::{CertumError, FromChecked},
+    super::flags::{StatusFlags, raise, raise_rounded, raise_rescaled, recording},
+    super::utils::{f64_pow2, rescale_parts, round_shr, cmp_parts, add_parts, div_is_exact, parts_to_float, float_to_parts, is_exact},
+    decimal::{ParseCertumError, ParseErrorKind, parse_decimal, write_decimal},
+    roots::{sqrt_bits, rsqrt_bits, cbrt_bits},
+    point::FixedPoint,
+    signed::Fixed,
+    unsigned::UFixed,
+};
+
+// NumCast is implemented through its full path, as importing it makes every `from` call ambiguous
+#[cfg(feature = "num-traits")]
+use num_traits::{
+    Zero, One, Num, Bounded, Signed, Unsigned, FromPrimitive, ToPrimitive,
+    CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, Saturating, SaturatingAdd, SaturatingSub, SaturatingMul,
+};
+
+// Constants and Helpers
+
+layout_signed!(Fixed,    u8, i8);
+layout_signed!(Fixed,    u16, i16);
+layout_signed!(Fixed,    u32, i32);
+layout_signed!(Fixed,    u64, i64);
+layout_signed!(Fixed,    u128, i128);
+layout_unsigned!(UFixed, u8);
+layout_unsigned!(UFixed, u16);
+layout_unsigned!(UFixed, u32);
+layout_unsigned!(UFixed, u64);
+layout_unsigned!(UFixed, u128);
+
+// Float Casting
+
+float_scaled_signed!(Fixed,    u8, i8);
+float_scaled_signed!(Fixed,    u16, i16);
+float_scaled_signed!(Fixed,    u32, i32);
+float_scaled_signed!(Fixed,    u64, i64);
+float_scaled_signed!(Fixed,    u128, i128);
+float_scaled_unsigned!(UFixed, u8);
+float_scaled_unsigned!(UFixed, u16);
+float_scaled_unsigned!(UFixed, u32);
+float_scaled_unsigned!(UFixed, u64);
+float_scaled_unsigned!(UFixed, u128);
+float_casts!(Fixed,  u8);
+float_casts!(Fixed,  u16);
+float_casts!(Fixed,  u32);
+float_casts!(Fixed,  u64);
+float_casts!(Fixed,  u128);
+float_casts!(UFixed, u8);
+float_casts!(UFixed, u16);
+float_casts!(UFixed, u32);
+float_casts!(UFixed, u64);
+float_casts!(UFixed, u128);
+
+// Optional half and quad precision floats
+
+#[cfg(feature = "f16")] float_casts_ieee!(Fixed, u8, f16, u16, 10, 5);
+#[cfg(feature = "f16")] float_casts_ieee!(Fixed, u16, f16, u16, 10, 5);
+#[cfg(feature = "f16")] float_casts_ieee!(Fixed, u32, f16, u16, 10, 5);
+#[cfg(feature = "f16")] float_casts_ieee!(Fixed, u64, f16, u16, 10, 5);
+#[cfg(feature = "f16")] float_casts_ieee!(Fixed, u128, f16, u16, 10, 5);
+#[cfg(feature = "f16")] float_casts_ieee!(UFixed, u8, f16, u16, 10, 5);
+#[cfg(feature = "f16")] float_casts_ieee!(UFixed, u16, f16, u16, 10, 5);
+#[cfg(feature = "f16")] float_casts_ieee!(UFixed, u32, f16, u16, 10, 5);
+#[cfg(feature = "f16")] float_casts_ieee!(UFixed, u64, f16, u16, 10, 5);
+#[cfg(feature = "f16")] float_casts_ieee!(UFixed, u128, f16, u16, 10, 5);
+#[cfg(feature = "f128")] float_casts_ieee!(Fixed, u8, f128, u128, 112, 15);
+#[cfg(feature = "f128")] float_casts_ieee!(Fixed, u16, f128, u128, 112, 15);
+#[cfg(feature = "f128")] float_casts_ieee!(Fixed, u32, f128, u128, 112, 15);
+#[cfg(feature = "f128")] float_casts_ieee!(Fixed, u64, f128, u128, 112, 15);
+#[cfg(feature = "f128")] float_casts_ieee!(Fixed, u128, f128, u128, 112, 15);
+#[cfg(feature = "f128")] float_casts_ieee!(UFixed, u8, f128, u128, 112, 15);
+#[cfg(feature = "f128")] float_casts_ieee!(UFixed, u16, f128, u128, 112, 15);
+#[cfg(feature = "f128")] float_casts_ieee!(UFixed, u32, f128, u128, 112, 15);
+#[cfg(feature = "f128")] float_casts_ieee!(UFixed, u64, f128, u128, 112, 15);
+#[cfg(feature = "f128")] float_casts_ieee!(UFixed, u128, f128, u128, 112, 15);
+
+// Decimal Formatting and Parsing
+
+decimal_signed!(Fixed,    u8, i8);
+decimal_signed!(Fixed,    u16, i16);
+decimal_signed!(Fixed,    u32, i32);
+decimal_signed!(Fixed,    u64, i64);
+decimal_signed!(Fixed,    u128, i128);
+decimal_unsigned!(UFixed, u8);
+decimal_unsigned!(UFixed, u16);
+decimal_unsigned!(UFixed, u32);
+decimal_unsigned!(UFixed, u64);
+decimal_unsigned!(UFixed, u128);
+
+// Arbitrary Length Conversion
+
+aln_signed!(Fixed,    u8, i8);
+aln_signed!(Fixed,    u16, i16);
+aln_signed!(Fixed,    u32, i32);
+aln_signed!(Fixed,    u64, i64);
+aln_signed!(Fixed,    u128, i128);
+aln_unsigned!(UFixed, u8);
+aln_unsigned!(UFixed, u16);
+aln_unsigned!(UFixed, u32);
+aln_unsigned!(UFixed, u64);
+aln_unsigned!(UFixed, u128);
+
+// Quire Accumulation
+
+quire_signed!(Fixed,    u8, i8);
+quire_signed!(Fixed,    u16, i16);
+quire_signed!(Fixed,    u32, i32);
+quire_signed!(Fixed,    u64, i64);
+quire_signed!(Fixed,    u128, i128);
+quire_unsigned!(UFixed, u8);
+quire_unsigned!(UFixed, u16);
+quire_unsigned!(UFixed, u32);
+quire_unsigned!(UFixed, u64);
+quire_unsigned!(UFixed, u128);
+
+// Negation
+
+negate!(Fixed,  u8);
+negate!(Fixed,  u16);
+negate!(Fixed,  u32);
+negate!(Fixed,  u64);
+negate!(Fixed,  u128);
+negate!(UFixed, u8);
+negate!(UFixed, u16);
+negate!(UFixed, u32);
+negate!(UFixed, u64);
+negate!(UFixed, u128);
+
+// Type Conversion
+
+from_direct!(Fixed, UFixed, u8);
+from_direct!(Fixed, UFixed, u16);
+from_direct!(Fixed, UFixed, u32);
+from_direct!(Fixed, UFixed, u64);
+from_direct!(Fixed, UFixed, u128);
+from_direct!(UFixed, Fixed, u8);
+from_direct!(UFixed, Fixed, u16);
+from_direct!(UFixed, Fixed, u32);
+from_direct!(UFixed, Fixed, u64);
+from_direct!(UFixed, Fixed, u128);
+
+// Between storage types
+from_storage_signed!(Fixed, u8, u16);
+from_storage_signed!(Fixed, u8, u32);
+from_storage_signed!(Fixed, u8, u64);
+from_storage_signed!(Fixed, u8, u128);
+from_storage_signed!(Fixed, u16, u8);
+from_storage_signed!(Fixed, u16, u32);
+from_storage_signed!(Fixed, u16, u64);
+from_storage_signed!(Fixed, u16, u128);
+from_storage_signed!(Fixed, u32, u8);
+from_storage_signed!(Fixed, u32, u16);
+from_storage_signed!(Fixed, u32, u64);
+from_storage_signed!(Fixed, u32, u128);
+from_storage_signed!(Fixed, u64, u8);
+from_storage_signed!(Fixed, u64, u16);
+from_storage_signed!(Fixed, u64, u32);
+from_storage_signed!(Fixed, u64, u128);
+from_storage_signed!(Fixed, u128, u8);
+from_storage_signed!(Fixed, u128, u16);
+from_storage_signed!(Fixed, u128, u32);
+from_storage_signed!(Fixed, u128, u64);
+
+from_storage!(UFixed, u8, u16);
+from_storage!(UFixed, u8, u32);
+from_storage!(UFixed, u8, u64);
+from_storage!(UFixed, u8, u128);
+from_storage!(UFixed, u16, u8);
+from_storage!(UFixed, u16, u32);
+from_storage!(UFixed, u16, u64);
+from_storage!(UFixed, u16, u128);
+from_storage!(UFixed, u32, u8);
+from_storage!(UFixed, u32, u16);
+from_storage!(UFixed, u32, u64);
+from_storage!(UFixed, u32, u128);
+from_storage!(UFixed, u64, u8);
+from_storage!(UFixed, u64, u16);
+from_storage!(UFixed, u64, u32);
+from_storage!(UFixed, u64, u128);
+from_storage!(UFixed, u128, u8);
+from_storage!(UFixed, u128, u16);
+from_storage!(UFixed, u128, u32);
+from_storage!(UFixed, u128, u64);
+
+// Checks and Comparisons
+
+comparison_solo_signed!(Fixed,     u8);
+comparison_solo_signed!(Fixed,     u16);
+comparison_solo_signed!(Fixed,     u32);
+comparison_solo_signed!(Fixed,     u64);
+comparison_solo_signed!(Fixed,     u128);
+comparison_solo_unsigned!(UFixed,  u8);
+comparison_solo_unsigned!(UFixed,  u16);
+comparison_solo_unsigned!(UFixed,  u32);
+comparison_solo_unsigned!(UFixed,  u64);
+comparison_solo_unsigned!(UFixed,  u128);
+
+equivalent_solo!(Fixed,  u8);
+equivalent_solo!(Fixed,  u16);
+equivalent_solo!(Fixed,  u32);
+equivalent_solo!(Fixed,  u64);
+equivalent_solo!(Fixed,  u128);
+equivalent_solo!(UFixed, u8);
+equivalent_solo!(UFixed, u16);
+equivalent_solo!(UFixed, u32);
+equivalent_solo!(UFixed, u64);
+equivalent_solo!(UFixed, u128);
+
+equivalent_bits!(Fixed,  u8);
+equivalent_bits!(Fixed,  u16);
+equivalent_bits!(Fixed,  u32);
+equivalent_bits!(Fixed,  u64);
+equivalent_bits!(Fixed,  u128);
+equivalent_bits!(UFixed, u8);
+equivalent_bits!(UFixed, u16);
+equivalent_bits!(UFixed, u32);
+equivalent_bits!(UFixed, u64);
+equivalent_bits!(UFixed, u128);
+
+equivalent_other!(Fixed, u16, Fixed, u8);
+equivalent_other!(Fixed, u32, Fixed, u8);
+equivalent_other!(Fixed, u32, Fixed, u16);
+equivalent_other!(Fixed, u64, Fixed, u8);
+equivalent_other!(Fixed, u64, Fixed, u16);
+equivalent_other!(Fixed, u64, Fixed, u32);
+equivalent_other!(Fixed, u128, Fixed, u8);
+equivalent_other!(Fixed, u128, Fixed, u16);
+equivalent_other!(Fixed, u128, Fixed, u32);
+equivalent_other!(Fixed, u128, Fixed, u64);
+equivalent_other!(UFixed, u16, UFixed, u8);
+equivalent_other!(UFixed, u32, UFixed, u8);
+equivalent_other!(UFixed, u32, UFixed, u16);
+equivalent_other!(UFixed, u64, UFixed, u8);
+equivalent_other!(UFixed, u64, UFixed, u16);
+equivalent_other!(UFixed, u64, UFixed, u32);
+equivalent_other!(UFixed, u128, UFixed, u8);
+equivalent_other!(UFixed, u128, UFixed, u16);
+equivalent_other!(UFixed, u128, UFixed, u32);
+equivalent_other!(UFixed, u128, UFixed, u64);
+equivalent_other!(Fixed, u8, UFixed, u8);
+equivalent_other!(Fixed, u8, UFixed, u16);
+equivalent_other!(Fixed, u8, UFixed, u32);
+equivalent_other!(Fixed, u8, UFixed, u64);
+equivalent_other!(Fixed, u8, UFixed, u128);
+equivalent_other!(Fixed, u16, UFixed, u8);
+equivalent_other!(Fixed, u16, UFixed, u16);
+equivalent_other!(Fixed, u16, UFixed, u32);
+equivalent_other!(Fixed, u16, UFixed, u64);
+equivalent_other!(Fixed, u16, UFixed, u128);
+equivalent_other!(Fixed, u32, UFixed, u8);
+equivalent_other!(Fixed, u32, UFixed, u16);
+equivalent_other!(Fixed, u32, UFixed, u32);
+equivalent_other!(Fixed, u32, UFixed, u64);
+equivalent_other!(Fixed, u32, UFixed, u128);
+equivalent_other!(Fixed, u64, UFixed, u8);
+equivalent_other!(Fixed, u64, UFixed, u16);
+equivalent_other!(Fixed, u64, UFixed, u32);
+equivalent_other!(Fixed, u64, UFixed, u64);
+equivalent_other!(Fixed, u64, UFixed, u128);
+equivalent_other!(Fixed, u128, UFixed, u8);
+equivalent_other!(Fixed, u128, UFixed, u16);
+equivalent_other!(Fixed, u128, UFixed, u32);
+equivalent_other!(Fixed, u128, UFixed, u64);
+equivalent_other!(Fixed, u128, UFixed, u128);
+
+// Algebra
+
+arith_signed!(Fixed,  u8, i8);
+arith_signed!(Fixed,  u16, i16);
+arith_signed!(Fixed,  u32, i32);
+arith_signed!(Fixed,  u64, i64);
+arith_signed!(Fixed,  u128, i128);
+arith_unsigned!(UFixed, u8);
+arith_unsigned!(UFixed, u16);
+arith_unsigned!(UFixed, u32);
+arith_unsigned!(UFixed, u64);
+arith_unsigned!(UFixed, u128);
+
+add_same!(Fixed,  u8, i8);
+add_same!(Fixed,  u16, i16);
+add_same!(Fixed,  u32, i32);
+add_same!(Fixed,  u64, i64);
+add_same!(Fixed,  u128, i128);
+add_same!(UFixed, u8, u8);
+add_same!(UFixed, u16, u16);
+add_same!(UFixed, u32, u32);
+add_same!(UFixed, u64, u64);
+add_same!(UFixed, u128, u128);
+
+sub_same!(Fixed,  u8, i8);
+sub_same!(Fixed,  u16, i16);
+sub_same!(Fixed,  u32, i32);
+sub_same!(Fixed,  u64, i64);
+sub_same!(Fixed,  u128, i128);
+sub_same!(UFixed, u8, u8);
+sub_same!(UFixed, u16, u16);
+sub_same!(UFixed, u32, u32);
+sub_same!(UFixed, u64, u64);
+sub_same!(UFixed, u128, u128);
+
+// Multiplication for u128 types is handled manually- Tuple quire needed
+
+mul_same_signed!(Fixed, u8, i8, u16);
+mul_same_signed!(Fixed, u16, i16, u32);
+mul_same_signed!(Fixed, u32, i32, u64);
+mul_same_signed!(Fixed, u64, i64, u128);
+from_u256!(Fixed);
+mul_same_signed_128!(Fixed, u128, i256, u256);
+mul_same_unsigned!(UFixed, u8, u16);
+mul_same_unsigned!(UFixed, u16, u32);
+mul_same_unsigned!(UFixed, u32, u64);
+mul_same_unsigned!(UFixed, u64, u128);
+from_u256!(UFixed);
+mul_same_unsigned_128!(UFixed, u128, u256);
+
+div_same_signed!(Fixed, u8, i8, u16);
+div_same_signed!(Fixed, u16, i16, u32);
+div_same_signed!(Fixed, u32, i32, u64);
+div_same_signed!(Fixed, u64, i64, u128);
+div_same_signed_128!(Fixed, u128, i128, u256);
+div_same_unsigned!(UFixed, u8, u16);
+div_same_unsigned!(UFixed, u16, u32);
+div_same_unsigned!(UFixed, u32, u64);
+div_same_unsigned!(UFixed, u64, u128);
+div_same_unsigned_128!(UFixed, u128, u256);
+
+rem_same!(Fixed,  u8, i8);
+rem_same!(Fixed,  u16, i16);
+rem_same!(Fixed,  u32, i32);
+rem_same!(Fixed,  u64, i64);
+rem_same!(Fixed,  u128, i128);
+rem_same!(UFixed, u8, u8);
+rem_same!(UFixed, u16, u16);
+rem_same!(UFixed, u32, u32);
+rem_same!(UFixed, u64, u64);
+rem_same!(UFixed, u128, u128);
+
+int_ops_signed!(Fixed,  u8, i8);
+int_ops_signed!(Fixed,  u16, i16);
+int_ops_signed!(Fixed,  u32, i32);
+int_ops_signed!(Fixed,  u64, i64);
+int_ops_signed!(Fixed,  u128, i128);
+int_ops_unsigned!(UFixed, u8);
+int_ops_unsigned!(UFixed, u16);
+int_ops_unsigned!(UFixed, u32);
+int_ops_unsigned!(UFixed, u64);
+int_ops_unsigned!(UFixed, u128);
+
+mixed_ops!(Fixed, u16, Fixed, u8);
+mixed_ops!(Fixed, u32, Fixed, u8);
+mixed_ops!(Fixed, u32, Fixed, u16);
+mixed_ops!(Fixed, u64, Fixed, u8);
+mixed_ops!(Fixed, u64, Fixed, u16);
+mixed_ops!(Fixed, u64, Fixed, u32);
+mixed_ops!(Fixed, u128, Fixed, u8);
+mixed_ops!(Fixed, u128, Fixed, u16);
+mixed_ops!(Fixed, u128, Fixed, u32);
+mixed_ops!(Fixed, u128, Fixed, u64);
+mixed_ops!(UFixed, u16, UFixed, u8);
+mixed_ops!(UFixed, u32, UFixed, u8);
+mixed_ops!(UFixed, u32, UFixed, u16);
+mixed_ops!(UFixed, u64, UFixed, u8);
+mixed_ops!(UFixed, u64, UFixed, u16);
+mixed_ops!(UFixed, u64, UFixed, u32);
+mixed_ops!(UFixed, u128, UFixed, u8);
+mixed_ops!(UFixed, u128, UFixed, u16);
+mixed_ops!(UFixed, u128, UFixed, u32);
+mixed_ops!(UFixed, u128, UFixed, u64);
+mixed_ops!(Fixed, u8, UFixed, u8);
+mixed_ops!(UFixed, u16, Fixed, u8);
+mixed_ops!(UFixed, u32, Fixed, u8);
+mixed_ops!(UFixed, u64, Fixed, u8);
+mixed_ops!(UFixed, u128, Fixed, u8);
+mixed_ops!(Fixed, u16, UFixed, u8);
+mixed_ops!(Fixed, u16, UFixed, u16);
+mixed_ops!(UFixed, u32, Fixed, u16);
+mixed_ops!(UFixed, u64, Fixed, u16);
+mixed_ops!(UFixed, u128, Fixed, u16);
+mixed_ops!(Fixed, u32, UFixed, u8);
+mixed_ops!(Fixed, u32, UFixed, u16);
+mixed_ops!(Fixed, u32, UFixed, u32);
+mixed_ops!(UFixed, u64, Fixed, u32);
+mixed_ops!(UFixed, u128, Fixed, u32);
+mixed_ops!(Fixed, u64, UFixed, u8);
+mixed_ops!(Fixed, u64, UFixed, u16);
+mixed_ops!(Fixed, u64, UFixed, u32);
+mixed_ops!(Fixed, u64, UFixed, u64);
+mixed_ops!(UFixed, u128, Fixed, u64);
+mixed_ops!(Fixed, u128, UFixed, u8);
+mixed_ops!(Fixed, u128, UFixed, u16);
+mixed_ops!(Fixed, u128, UFixed, u32);
+mixed_ops!(Fixed, u128, UFixed, u64);
+mixed_ops!(Fixed, u128, UFixed, u128);
+
+// Roots
+
+roots_signed!(Fixed,  u8, i8);
+roots_signed!(Fixed,  u16, i16);
+roots_signed!(Fixed,  u32, i32);
+roots_signed!(Fixed,  u64, i64);
+roots_signed!(Fixed,  u128, i128);
+roots_unsigned!(UFixed, u8);
+roots_unsigned!(UFixed, u16);
+roots_unsigned!(UFixed, u32);
+roots_unsigned!(UFixed, u64);
+roots_unsigned!(UFixed, u128);
+
+// Trigonometry
+
+trig_signed!(Fixed, u8, i8);
+trig_signed!(Fixed, u16, i16);
+trig_signed!(Fixed, u32, i32);
+trig_signed!(Fixed, u64, i64);
+trig_signed!(Fixed, u128, i128);
+
+// Exponentials and Logarithms
+
+exp_signed!(Fixed, u8, i8);
+exp_signed!(Fixed, u16, i16);
+exp_signed!(Fixed, u32, i32);
+exp_signed!(Fixed, u64, i64);
+exp_signed!(Fixed, u128, i128);
+exp_unsigned!(UFixed, u8);
+exp_unsigned!(UFixed, u16);
+exp_unsigned!(UFixed, u32);
+exp_unsigned!(UFixed, u64);
+exp_unsigned!(UFixed, u128);
+
+// Rounding and Decomposition
+
+decompose_signed!(Fixed, u8, i8);
+decompose_signed!(Fixed, u16, i16);
+decompose_signed!(Fixed, u32, i32);
+decompose_signed!(Fixed, u64, i64);
+decompose_signed!(Fixed, u128, i128);
+decompose_unsigned!(UFixed, u8);
+decompose_unsigned!(UFixed, u16);
+decompose_unsigned!(UFixed, u32);
+decompose_unsigned!(UFixed, u64);
+decompose_unsigned!(UFixed, u128);
+
+// ULP Navigation
+
+ulps_signed!(Fixed, u8, i8);
+ulps_signed!(Fixed, u16, i16);
+ulps_signed!(Fixed, u32, i32);
+ulps_signed!(Fixed, u64, i64);
+ulps_signed!(Fixed, u128, i128);
+ulps_unsigned!(UFixed, u8);
+ulps_unsigned!(UFixed, u16);
+ulps_unsigned!(UFixed, u32);
+ulps_unsigned!(UFixed, u64);
+ulps_unsigned!(UFixed, u128);
+
+// Fallible Conversions
+
+from_checked_float!(Fixed, u8, f64, 52, 11);
+from_checked_float!(Fixed, u8, f32, 23, 8);
+from_checked_float!(Fixed, u16, f64, 52, 11);
+from_checked_float!(Fixed, u16, f32, 23, 8);
+from_checked_float!(Fixed, u32, f64, 52, 11);
+from_checked_float!(Fixed, u32, f32, 23, 8);
+from_checked_float!(Fixed, u64, f64, 52, 11);
+from_checked_float!(Fixed, u64, f32, 23, 8);
+from_checked_float!(Fixed, u128, f64, 52, 11);
+from_checked_float!(Fixed, u128, f32, 23, 8);
+from_checked_float!(UFixed, u8, f64, 52, 11);
+from_checked_float!(UFixed, u8, f32, 23, 8);
+from_checked_float!(UFixed, u16, f64, 52, 11);
+from_checked_float!(UFixed, u16, f32, 23, 8);
+from_checked_float!(UFixed, u32, f64, 52, 11);
+from_checked_float!(UFixed, u32, f32, 23, 8);
+from_checked_float!(UFixed, u64, f64, 52, 11);
+from_checked_float!(UFixed, u64, f32, 23, 8);
+from_checked_float!(UFixed, u128, f64, 52, 11);
+from_checked_float!(UFixed, u128, f32, 23, 8);
+#[cfg(feature = "f16")] from_checked_float!(Fixed, u8, f16, 10, 5);
+#[cfg(feature = "f16")] from_checked_float!(Fixed, u16, f16, 10, 5);
+#[cfg(feature = "f16")] from_checked_float!(Fixed, u32, f16, 10, 5);
+#[cfg(feature = "f16")] from_checked_float!(Fixed, u64, f16, 10, 5);
+#[cfg(feature = "f16")] from_checked_float!(Fixed, u128, f16, 10, 5);
+#[cfg(feature = "f16")] from_checked_float!(UFixed, u8, f16, 10, 5);
+#[cfg(feature = "f16")] from_checked_float!(UFixed, u16, f16, 10, 5);
+#[cfg(feature = "f16")] from_checked_float!(UFixed, u32, f16, 10, 5);
+#[cfg(feature = "f16")] from_checked_float!(UFixed, u64, f16, 10, 5);
+#[cfg(feature = "f16")] from_checked_float!(UFixed, u128, f16, 10, 5);
+#[cfg(feature = "f128")] from_checked_float!(Fixed, u8, f128, 112, 15);
+#[cfg(feature = "f128")] from_checked_float!(Fixed, u16, f128, 112, 15);
+#[cfg(feature = "f128")] from_checked_float!(Fixed, u32, f128, 112, 15);
+#[cfg(feature = "f128")] from_checked_float!(Fixed, u64, f128, 112, 15);
+#[cfg(feature = "f128")] from_checked_float!(Fixed, u128, f128, 112, 15);
+#[cfg(feature = "f128")] from_checked_float!(UFixed, u8, f128, 112, 15);
+#[cfg(feature = "f128")] from_checked_float!(UFixed, u16, f128, 112, 15);
+#[cfg(feature = "f128")] from_checked_float!(UFixed, u32, f128, 112, 15);
+#[cfg(feature = "f128")] from_checked_float!(UFixed, u64, f128, 112, 15);
+#[cfg(feature = "f128")] from_checked_float!(UFixed, u128, f128, 112, 15);
+try_from_int!(Fixed, u8; i8, i16, i32, i64, i128; u16, u32, u64, u128);
+try_from_int!(Fixed, u16; i8, i16, i32, i64, i128; u8, u32, u64, u128);
+try_from_int!(Fixed, u32; i8, i16, i32, i64, i128; u8, u16, u64, u128);
+try_from_int!(Fixed, u64; i8, i16, i32, i64, i128; u8, u16, u32, u128);
+try_from_int!(Fixed, u128; i8, i16, i32, i64, i128; u8, u16, u32, u64);
+try_from_int!(UFixed, u8; i8, i16, i32, i64, i128; u16, u32, u64, u128);
+try_from_int!(UFixed, u16; i8, i16, i32, i64, i128; u8, u32, u64, u128);
+try_from_int!(UFixed, u32; i8, i16, i32, i64, i128; u8, u16, u64, u128);
+try_from_int!(UFixed, u64; i8, i16, i32, i64, i128; u8, u16, u32, u128);
+try_from_int!(UFixed, u128; i8, i16, i32, i64, i128; u8, u16, u32, u64);
+from_checked_layouts!(Fixed, u8; Fixed u8, Fixed u16, Fixed u32, Fixed u64, Fixed u128, UFixed u8, UFixed u16, UFixed u32, UFixed u64, UFixed u128);
+from_checked_layouts!(Fixed, u16; Fixed u8, Fixed u16, Fixed u32, Fixed u64, Fixed u128, UFixed u8, UFixed u16, UFixed u32, UFixed u64, UFixed u128);
+from_checked_layouts!(Fixed, u32; Fixed u8, Fixed u16, Fixed u32, Fixed u64, Fixed u128, UFixed u8, UFixed u16, UFixed u32, UFixed u64, UFixed u128);
+from_checked_layouts!(Fixed, u64; Fixed u8, Fixed u16, Fixed u32, Fixed u64, Fixed u128, UFixed u8, UFixed u16, UFixed u32, UFixed u64, UFixed u128);
+from_checked_layouts!(Fixed, u128; Fixed u8, Fixed u16, Fixed u32, Fixed u64, Fixed u128, UFixed u8, UFixed u16, UFixed u32, UFixed u64, UFixed u128);
+from_checked_layouts!(UFixed, u8; Fixed u8, Fixed u16, Fixed u32, Fixed u64, Fixed u128, UFixed u8, UFixed u16, UFixed u32, UFixed u64, UFixed u128);
+from_checked_layouts!(UFixed, u16; Fixed u8, Fixed u16, Fixed u32, Fixed u64, Fixed u128, UFixed u8, UFixed u16, UFixed u32, UFixed u64, UFixed u128);
+from_checked_layouts!(UFixed, u32; Fixed u8, Fixed u16, Fixed u32, Fixed u64, Fixed u128, UFixed u8, UFixed u16, UFixed u32, UFixed u64, UFixed u128);
+from_checked_layouts!(UFixed, u64; Fixed u8, Fixed u16, Fixed u32, Fixed u64, Fixed u128, UFixed u8, UFixed u16, UFixed u32, UFixed u64, UFixed u128);
+from_checked_layouts!(UFixed, u128; Fixed u8, Fixed u16, Fixed u32, Fixed u64, Fixed u128, UFixed u8, UFixed u16, UFixed u32, UFixed u64, UFixed u128);
+
+// Fixed-Point Layout Access
+
+fixed_point!(Fixed, u8);
+fixed_point!(Fixed, u16);
+fixed_point!(Fixed, u32);
+fixed_point!(Fixed, u64);
+fixed_point!(Fixed, u128);
+fixed_point!(UFixed, u8);
+fixed_point!(UFixed, u16);
+fixed_point!(UFixed, u32);
+fixed_point!(UFixed, u64);
+fixed_point!(UFixed, u128);
+
+// Optional num-traits Implementations
+
+#[cfg(feature = "num-traits")] num_traits_common!(Fixed, u8);
+#[cfg(feature = "num-traits")] num_traits_common!(Fixed, u16);
+#[cfg(feature = "num-traits")] num_traits_common!(Fixed, u32);
+#[cfg(feature = "num-traits")] num_traits_common!(Fixed, u64);
+#[cfg(feature = "num-traits")] num_traits_common!(Fixed, u128);
+#[cfg(feature = "num-traits")] num_traits_common!(UFixed, u8);
+#[cfg(feature = "num-traits")] num_traits_common!(UFixed, u16);
+#[cfg(feature = "num-traits")] num_traits_common!(UFixed, u32);
+#[cfg(feature = "num-traits")] num_traits_common!(UFixed, u64);
+#[cfg(feature = "num-traits")] num_traits_common!(UFixed, u128);
+#[cfg(feature = "num-traits")] num_traits_signed!(Fixed, u8, i8);
+#[cfg(feature = "num-traits")] num_traits_signed!(Fixed, u16, i16);
+#[cfg(feature = "num-traits")] num_traits_signed!(Fixed, u32, i32);
+#[cfg(feature = "num-traits")] num_traits_signed!(Fixed, u64, i64);
+#[cfg(feature = "num-traits")] num_traits_signed!(Fixed, u128, i128);
+#[cfg(feature = "num-traits")] num_traits_unsigned!(UFixed, u8);
+#[cfg(feature = "num-traits")] num_traits_unsigned!(UFixed, u16);
+#[cfg(feature = "num-traits")] num_traits_unsigned!(UFixed, u32);
+#[cfg(feature = "num-traits")] num_traits_unsigned!(UFixed, u64);
+#[cfg(feature = "num-traits")] num_traits_unsigned!(UFixed, u128);
@@ -0,0 +1,14 @@
+/// The layout of a fixed-point type, for generic code written over every storage and fraction width
+///
+/// Implemented for every `Fixed<S, FRAC>` and `UFixed<S, FRAC>`, so every named type
+pub trait FixedPoint: Copy {
+    /// The unsigned integer holding the raw bits
+    type Bits: Copy;
+    /// The number of fraction bits
+    const FRAC: u32;
+
+    /// Build from raw bits
+    fn from_bits(bits: Self::Bits) -> Self;
+    /// The raw bits
+    fn to_bits(self) -> Self::Bits;
+}
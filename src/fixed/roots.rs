@@ -0,0 +1,93 @@
+use crate::quire::quire256::u256;
+
+/// A 512-bit product or power of two as (high, low) halves, ordered like the full value
+type Wide = (u256, u256);
+
+/// Full product of two u256's
+fn wide_mul(lhs: u256, rhs: u256) -> Wide {
+    let (lo, hi) = lhs.widening_mul(rhs);
+    (hi, lo)
+}
+
+/// A u256 shifted left into 512 bits. `shift` must be below 256
+fn wide_shl(val: u256, shift: u32) -> Wide {
+    if shift == 0 { return (u256::MIN, val); }
+    let wide = shift as u128;
+    (val >> (256 - wide), val << wide)
+}
+
+/// Cube of a value below 2^170 into 512 bits
+fn wide_cube(val: u256) -> Wide {
+    let (sq_hi, sq_lo) = wide_mul(val, val);
+    let (hi, lo) = wide_mul(sq_lo, val);
+    (hi + sq_hi * val, lo)
+}
+
+/// Square root of a magnitude with `frac` fraction bits, correctly rounded to `frac` fraction bits
+///
+/// The result can need one bit more than the storage, so it is returned as a u256
+pub fn sqrt_bits(mag: u128, frac: u32) -> u256 {
+    // sqrt(m / 2^f) * 2^f = sqrt(m * 2^f)
+    let num = u256::from(mag) << frac as u128;
+    let root = num.isqrt();
+    // Round up when num lies above (root + 1/2)^2, which is never an integer
+    if num - root * root > root { root + 1 } else { root }
+}
+
+/// Reciprocal square root of a nonzero magnitude with `frac` fraction bits, correctly rounded to `frac` fraction bits
+///
+/// Returns None when the result does not fit in a u128
+pub fn rsqrt_bits(mag: u128, frac: u32) -> Option<u128> {
+    // 2^f / sqrt(m / 2^f) = sqrt(2^3f / m)
+    let exp = 3 * frac;
+    let den = u256::from(mag);
+    // Long division of 2^3f by m in steps that keep the remainder within 256 bits
+    let first = exp.min(255);
+    let mut quo = (u256::from(1) << first as u128) / den;
+    let mut rem = (u256::from(1) << first as u128) % den;
+    let mut left = exp - first;
+    while left > 0 {
+        let step = left.min(127);
+        if quo.leading_zeros() < step { return None; }
+        quo = (quo << step as u128) + (rem << step as u128) / den;
+        rem = (rem << step as u128) % den;
+        left -= step;
+    }
+    let root = quo.isqrt();
+    // Round up when 2^(3f + 2) > m * (2 * root + 1)^2
+    let odd = (root << 1) + 1;
+    let (sq_hi, sq_lo) = wide_mul(odd, odd);
+    let (hi, lo) = wide_mul(sq_lo, den);
+    let rhs = (hi + sq_hi * den, lo);
+    let lhs = if exp + 2 >= 256 {
+        (u256::from(1) << (exp + 2 - 256) as u128, u256::MIN)
+    } else {
+        (u256::MIN, u256::from(1) << (exp + 2) as u128)
+    };
+    let root = if lhs > rhs { root + 1 } else { root };
+    if root > u128::MAX { None } else { Some(u128::from(root)) }
+}
+
+/// Cube root of a magnitude with `frac` fraction bits, correctly rounded to `frac` fraction bits
+///
+/// The result can need one bit more than the storage, so it is returned as a u256
+pub fn cbrt_bits(mag: u128, frac: u32) -> u256 {
+    if mag == 0 { return u256::MIN; }
+    // cbrt(m / 2^f) * 2^f = cbrt(m * 2^2f)
+    let num = if 2 * frac >= 256 {
+        (u256::from(mag) << (2 * frac - 256) as u128, u256::MIN)
+    } else {
+        wide_shl(u256::from(mag), 2 * frac)
+    };
+    let log = if num.0 == u256::MIN { num.1.ilog2() } else { 256 + num.0.ilog2() };
+    // Set the bits of the root from the top, keeping each one whose cube stays within num
+    let mut root = u256::MIN;
+    for bit in (0..=log / 3).rev() {
+        let cand = root | (u256::from(1) << bit as u128);
+        if wide_cube(cand) <= num { root = cand; }
+    }
+    // Round up when 8 * num > (2 * root + 1)^3, which is never equal
+    let (hi, lo) = num;
+    let num8 = ((hi << 3) | (lo >> 253), lo << 3);
+    if num8 > wide_cube((root << 1) + 1) { root + 1 } else { root }
+}
@@ -0,0 +1,16 @@
+#[derive(Copy, Clone, Debug)]
+/// A generic signed fixed-point number
+/// 
+/// `S` is the unsigned integer holding the raw bits (u8 to u128) and `FRAC` is the number of fraction bits.
+/// The remaining bits are 1 sign bit and `S::BITS - FRAC - 1` integer bits, so `FRAC` can be at most `S::BITS - 1`.
+/// 
+/// Every signed certum, quarta, dimid and acute is an alias of this type, `c32` is `Fixed<u32, 28>`.
+/// Any other split works the same way, `Fixed<u32, 20>` has 11 integer bits and 20 fraction bits.
+/// 
+/// Operators saturate: `+`, `-`, `*` and `/` clamp results out of range to MIN/MAX.
+/// `*`, `/` and narrowing conversions round to nearest (ties to even), `mul_round` and `FromRound` select other modes.
+/// Use the `checked_`, `wrapping_` and `overflowing_` methods for other overflow behaviour.
+pub struct Fixed<S, const FRAC: u32> {
+    /// The raw bits of the value, in two's complement
+    pub bits: S
+}
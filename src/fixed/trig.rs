@@ -0,0 +1,144 @@
+use crate::quire::quire256::u256;
+use super::wide::{self, FRAC, ONE, HALF_PI, PI};
+
+/// π / 4 at working precision
+const QUARTER_PI: u256 = u256 { bits: [0xC90FDAA22168C234, 0xC4C6628B80DC1CD129024E088A67CC74] };
+/// π / 6 at working precision
+const SIXTH_PI: u256 = u256 { bits: [0x860A91C16B9B2C23, 0x2DD99707AB3D688B70AC3405B19A884D] };
+/// √3 at working precision
+const SQRT_3: u256 = u256 { bits: [0x1BB67AE8584CAA73B, 0x25742D7078B83B8925D834CC53DA4798] };
+/// tan(π / 12) = 2 - √3 at working precision
+const TAN_PI_12: u256 = u256 { bits: [0x4498517A7B3558C4, 0xDA8BD28F8747C476DA27CB33AC25B868] };
+/// 2 / π with 384 fraction bits, little-endian limbs
+const TWO_OVER_PI: [u128; 3] = [
+    0xb7246e3a424dd2e006492eea09d1921c,
+    0xdb6295993c439041fe5163abdebbc561,
+    0xa2f9836e4e441529fc2757d1f534ddc0,
+];
+
+/// The 256 bits of a little-endian 512-bit value starting at bit `start`
+fn window(val: &[u128; 4], start: u32) -> u256 {
+    let (limb, off) = ((start / 128) as usize, start % 128);
+    let get = |i: usize| if i < 4 { val[i] } else { 0 };
+    let part = |i: usize| if off == 0 { get(i) } else { (get(i) >> off) | (get(i + 1) << (128 - off)) };
+    u256 { bits: [part(limb + 1), part(limb)] }
+}
+
+/// Reduce a magnitude with `frac` fraction bits modulo π / 2
+///
+/// Multiplies by a 384-bit 2 / π, so even the largest 128-bit integers reduce exactly enough.
+/// Returns the quadrant and the remaining angle in [0, π / 2) at working precision
+fn reduce(mag: u128, frac: u32) -> (u32, u256) {
+    let mut prod = [0u128; 4];
+    let mut carry = 0;
+    for (i, limb) in TWO_OVER_PI.iter().enumerate() {
+        let (lo, hi) = mag.carrying_mul(*limb, carry);
+        prod[i] = lo;
+        carry = hi;
+    }
+    prod[3] = carry;
+    // The product has 384 + frac fraction bits
+    let point = 384 + frac;
+    let quadrant = (window(&prod, point).bits[1] & 3) as u32;
+    let fract = window(&prod, point - FRAC) & (ONE - 1);
+    (quadrant, wide::mul(fract, HALF_PI))
+}
+
+/// Sine and cosine of an angle in [0, π / 4] from their Taylor series
+fn taylor(angle: u256) -> (u256, u256) {
+    let (mut sin_pos, mut sin_neg) = (angle, u256::MIN);
+    let (mut cos_pos, mut cos_neg) = (ONE, u256::MIN);
+    // angle^k / k!, until it drops below the working precision
+    let mut term = angle;
+    let mut k = 2u128;
+    loop {
+        term = wide::mul(term, angle) / k;
+        if term == u256::MIN { break; }
+        match k % 4 {
+            0 => cos_pos += term,
+            1 => sin_pos += term,
+            2 => cos_neg += term,
+            _ => sin_neg += term,
+        }
+        k += 1;
+    }
+    (sin_pos - sin_neg, cos_pos - cos_neg)
+}
+
+/// Sine and cosine of a signed magnitude with `frac` fraction bits, as signs and working values
+pub fn sin_cos(neg: bool, mag: u128, frac: u32) -> ((bool, u256), (bool, u256)) {
+    let (quadrant, angle) = reduce(mag, frac);
+    let (s, c) = if angle > QUARTER_PI {
+        let (s, c) = taylor(HALF_PI - angle);
+        (c, s)
+    } else {
+        taylor(angle)
+    };
+    let (sin, cos) = match quadrant {
+        0 => ((false, s), (false, c)),
+        1 => ((false, c), (true, s)),
+        2 => ((true, s), (true, c)),
+        _ => ((true, c), (false, s)),
+    };
+    ((sin.0 ^ neg, sin.1), cos)
+}
+
+/// Arctangent of a value in [0, tan(π / 12)] from its Taylor series
+fn atan_series(val: u256) -> u256 {
+    let square = wide::mul(val, val);
+    let (mut pos, mut neg) = (val, u256::MIN);
+    let mut term = val;
+    let mut k = 1u128;
+    loop {
+        term = wide::mul(term, square);
+        let part = term / (2 * k + 1);
+        if part == u256::MIN { break; }
+        if k % 2 == 1 { neg += part; } else { pos += part; }
+        k += 1;
+    }
+    pos - neg
+}
+
+/// Arctangent of a working value in [0, 1]
+fn atan_unit(val: u256) -> u256 {
+    if val <= TAN_PI_12 { return atan_series(val); }
+    // atan(v) = π / 6 + atan((√3 v - 1) / (√3 + v))
+    let scaled = wide::mul(SQRT_3, val);
+    let den = SQRT_3 + val;
+    if scaled >= ONE {
+        SIXTH_PI + atan_series(wide::div(scaled - ONE, den))
+    } else {
+        SIXTH_PI - atan_series(wide::div(ONE - scaled, den))
+    }
+}
+
+/// Arctangent of a magnitude with `frac` fraction bits, in [0, π / 2] at working precision
+pub fn atan(mag: u128, frac: u32) -> u256 {
+    if frac == 128 || mag <= 1 << frac {
+        atan_unit(wide::from_bits(mag, frac))
+    } else {
+        // atan(x) = π / 2 - atan(1 / x)
+        HALF_PI - atan_unit(wide::shl_div(u256::from(1), u256::from(mag), frac + FRAC))
+    }
+}
+
+/// Angle of the point (x, y) given as signs and magnitudes of the same scale, in [-π, π] at working precision
+pub fn atan2(yneg: bool, y: u256, xneg: bool, x: u256) -> (bool, u256) {
+    if y == u256::MIN && x == u256::MIN { return (false, u256::MIN); }
+    let base = if y <= x {
+        atan_unit(wide::div(y, x))
+    } else {
+        HALF_PI - atan_unit(wide::div(x, y))
+    };
+    let angle = if xneg { PI - base } else { base };
+    (yneg && angle != u256::MIN, angle)
+}
+
+/// √(1 - v²) of a magnitude in [0, 1] with `frac` fraction bits, and the magnitude itself at working precision
+///
+/// Returns None when the magnitude is above one
+pub fn unit_complement(mag: u128, frac: u32) -> Option<(u256, u256)> {
+    if frac < 128 && mag > 1 << frac { return None; }
+    let val = wide::from_bits(mag, frac);
+    Some((val, wide::sqrt(wide::mul(ONE - val, ONE + val))))
+}
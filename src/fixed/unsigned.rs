@@ -0,0 +1,16 @@
+#[derive(Copy, Clone, Debug)]
+/// A generic unsigned fixed-point number
+/// 
+/// `S` is the unsigned integer holding the raw bits (u8 to u128) and `FRAC` is the number of fraction bits.
+/// The remaining `S::BITS - FRAC` bits are integer bits, so `FRAC` can be at most `S::BITS`.
+/// 
+/// Every unsigned certum, quarta, dimid and acute is an alias of this type, `uc32` is `UFixed<u32, 28>`.
+/// Any other split works the same way, `UFixed<u32, 20>` has 12 integer bits and 20 fraction bits.
+/// 
+/// Operators saturate: `+`, `-`, `*` and `/` clamp results out of range to MIN/MAX.
+/// `*`, `/` and narrowing conversions round to nearest (ties to even), `mul_round` and `FromRound` select other modes.
+/// Use the `checked_`, `wrapping_` and `overflowing_` methods for other overflow behaviour.
+pub struct UFixed<S, const FRAC: u32> {
+    /// The raw bits of the value
+    pub bits: S
+}
@@ -0,0 +1,85 @@
+use std::cmp::Ordering;
+use crate::{quire::quire256::u256, rounding::RoundingMode};
+
+/// Fraction bits of the working precision used by the elementary functions
+///
+/// Working values are unsigned u256's below 2^64, so every 8 to 128-bit layout keeps
+/// at least 64 guard bits below its last fraction bit
+pub const FRAC: u32 = 192;
+/// One at working precision
+pub const ONE: u256 = u256 { bits: [1 << 64, 0] };
+/// π / 2 at working precision
+pub const HALF_PI: u256 = u256 { bits: [0x1921FB54442D18469, 0x898CC51701B839A252049C1114CF98E8] };
+/// π at working precision
+pub const PI: u256 = u256 { bits: [0x3243F6A8885A308D3, 0x13198A2E03707344A4093822299F31D0] };
+
+/// Whether a magnitude with `frac` fraction bits is below 2^64, so `from_bits` can hold it
+pub fn fits(mag: u128, frac: u32) -> bool {
+    mag.checked_shr(frac).unwrap_or(0) >> 64 == 0
+}
+
+/// Convert a magnitude with `frac` fraction bits to working precision.
+///
+/// The value must be below 2^64
+pub fn from_bits(mag: u128, frac: u32) -> u256 {
+    u256::from(mag) << (FRAC - frac) as u128
+}
+
+/// Round `val * 2^exp` from working precision to `frac` fraction bits with `mode`. `neg` is the sign of the value
+///
+/// Returns None when the rounded magnitude does not fit in a u128
+pub fn round(val: u256, exp: i64, frac: u32, neg: bool, mode: RoundingMode) -> Option<u128> {
+    if val == u256::MIN { return Some(0); }
+    let shift = FRAC as i64 - frac as i64 - exp;
+    if val.ilog2() as i64 - shift >= 128 { return None; }
+    if shift <= 0 { return Some(u128::from(val << -shift as u128)); }
+    let (trunc, half, inexact) = if shift > 256 {
+        (u256::MIN, Ordering::Less, true)
+    } else if shift == 256 {
+        (u256::MIN, val.cmp(&(u256::from(1) << 255)), true)
+    } else {
+        let wide = shift as u128;
+        let rem = val & ((u256::from(1) << wide) - 1);
+        (val >> wide, rem.cmp(&(u256::from(1) << (wide - 1))), rem != u256::MIN)
+    };
+    let up = mode.round_up(neg, trunc.bits[1] & 1 == 1, half, inexact);
+    u128::from(trunc).checked_add(up as u128)
+}
+
+/// Product of two working values, truncated
+pub fn mul(lhs: u256, rhs: u256) -> u256 {
+    let (lo, hi) = lhs.widening_mul(rhs);
+    (hi << 64) | (lo >> FRAC as u128)
+}
+
+/// Quotient of two working values, truncated and saturating at u256::MAX
+pub fn div(num: u256, den: u256) -> u256 {
+    shl_div(num, den, FRAC)
+}
+
+/// `num * 2^shift / den` rounded down and saturating at u256::MAX. `den` must be nonzero and below 2^255
+pub fn shl_div(num: u256, den: u256, shift: u32) -> u256 {
+    let (mut quo, mut rem) = num.div_rem(den);
+    let mut left = shift;
+    // Bring down as many bits at a time as the remainder has room for
+    while left > 0 {
+        let step = left.min(den.leading_zeros()).min(127);
+        if quo.leading_zeros() < step { return u256::MAX; }
+        let (q, r) = (rem << step as u128).div_rem(den);
+        quo = (quo << step as u128) | q;
+        rem = r;
+        left -= step;
+    }
+    quo
+}
+
+/// Square root of a working value below 4
+pub fn sqrt(val: u256) -> u256 {
+    if val == u256::MIN { return val; }
+    // A root with 127 fraction bits, refined twice with Newton's method
+    let mut root = (val << 62).isqrt() << 65;
+    for _ in 0..2 {
+        root = (root + div(val, root)) >> 1;
+    }
+    root
+}
@@ -0,0 +1,117 @@
+use std::{cell::Cell, ops::{BitOr, BitOrAssign}};
+use crate::{error::CertumError, utils::is_exact};
+
+/// Sticky status flags raised by arithmetic, like the IEEE 754 exception flags
+///
+/// Flags are only recorded inside a `with_flags` scope
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StatusFlags(u8);
+
+impl StatusFlags {
+    /// No flags raised
+    pub const NONE: Self = Self(0);
+    /// A result was out of range and saturated at MIN/MAX
+    pub const OVERFLOW: Self = Self(1);
+    /// A nonzero result rounded to zero
+    pub const UNDERFLOW: Self = Self(1 << 1);
+    /// A result was rounded or saturated
+    pub const INEXACT: Self = Self(1 << 2);
+    /// A value was divided by zero
+    pub const DIVIDE_BY_ZERO: Self = Self(1 << 3);
+
+    /// Whether every flag in `other` is raised
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether no flag is raised
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The most severe raised flag as an error, or Ok when none is raised
+    ///
+    /// Divide-by-zero comes first, then overflow, underflow and inexact (as PrecisionLoss)
+    pub const fn check(self) -> Result<(), CertumError> {
+        if self.contains(Self::DIVIDE_BY_ZERO) { return Err(CertumError::DivideByZero); }
+        if self.contains(Self::OVERFLOW) { return Err(CertumError::Overflow); }
+        if self.contains(Self::UNDERFLOW) { return Err(CertumError::Underflow); }
+        if self.contains(Self::INEXACT) { return Err(CertumError::PrecisionLoss); }
+        Ok(())
+    }
+}
+
+impl BitOr for StatusFlags {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for StatusFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+thread_local! {
+    /// Number of nested with_flags scopes on this thread
+    static DEPTH: Cell<u32> = const { Cell::new(0) };
+    /// Flags raised in the innermost scope
+    static FLAGS: Cell<StatusFlags> = const { Cell::new(StatusFlags::NONE) };
+}
+
+/// Whether a with_flags scope is active on this thread
+pub(crate) fn recording() -> bool {
+    DEPTH.with(|depth| depth.get() > 0)
+}
+
+/// Raise flags in the innermost with_flags scope. Does nothing outside of one
+pub(crate) fn raise(flags: StatusFlags) {
+    if recording() {
+        FLAGS.with(|cell| cell.set(cell.get() | flags));
+    }
+}
+
+/// Raise the inexact flag for a rounded result, and the underflow flag too when it rounded to zero
+pub(crate) fn raise_rounded(zero: bool) {
+    raise(if zero { StatusFlags::INEXACT | StatusFlags::UNDERFLOW } else { StatusFlags::INEXACT });
+}
+
+/// Raise the flags for a magnitude with `from` fraction bits moved to `to` fraction bits and clamped to `max`,
+/// where `wide` is the rescaled magnitude before clamping
+pub(crate) fn raise_rescaled(mag: u128, from: u32, to: u32, wide: u128, max: u128) {
+    // Widening only reaches u128::MAX by saturating, as the shifted-in bits are zero
+    if wide > max || (wide == u128::MAX && from < to) {
+        raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    } else if !is_exact(mag, from, to) {
+        raise_rounded(wide == 0);
+    }
+}
+
+/// Restores the enclosing scope when a with_flags scope ends, even by panicking
+struct Scope {
+    outer: StatusFlags,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        // Sticky: the enclosing scope also sees everything raised inside this one
+        FLAGS.with(|cell| cell.set(self.outer | cell.get()));
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Run `f`, returning its result and the status flags raised by arithmetic inside it on this thread
+///
+/// Scopes nest, and flags raised in an inner scope are also raised in the enclosing ones.
+/// Outside of any scope nothing is recorded
+pub fn with_flags<R>(f: impl FnOnce() -> R) -> (R, StatusFlags) {
+    let outer = FLAGS.with(|cell| cell.replace(StatusFlags::NONE));
+    DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let scope = Scope { outer };
+    let res = f();
+    let flags = FLAGS.with(|cell| cell.get());
+    drop(scope);
+    (res, flags)
+}
@@ -1,19 +1,34 @@
 // #![deny(missing_docs)]
+#![cfg_attr(feature = "f16", feature(f16))]
+#![cfg_attr(feature = "f128", feature(f128))]
 pub mod utils;
+pub mod fixed;
 pub mod certum;
 pub mod macros;
 pub mod quire;
 pub mod allen;
+pub mod rounding;
+pub mod error;
+pub mod flags;
+pub mod quarta;
+pub mod dimid;
+pub mod acute;
 
 #[cfg(test)]
 pub mod tests;
-// pub mod dimid;
-// pub mod quarta;
-// pub mod acute;
-
-pub use allen::allen::ALN;
+pub use allen::allen::{ALN, RoundingReport};
+pub use rounding::{RoundingMode, FromRound};
+pub use error::{CertumError, FromChecked};
+pub use flags::{StatusFlags, with_flags};
+// Expose the generic layouts to top-level crate
+pub use fixed::signed::Fixed;
+pub use fixed::unsigned::UFixed;
+pub use fixed::point::FixedPoint;
+pub use fixed::decimal::{ParseCertumError, ParseErrorKind};
 // Expose u256 to top-level crate
 pub use quire::quire256::u256;
+pub use quire::i256::i256;
+pub use quire::accumulator::Quire;
 // Expose c8-c128 to top-level crate
 pub use certum::certum8::c8;
 pub use certum::certum16::c16;
@@ -25,4 +40,43 @@ pub use certum::u_certum8::uc8;
 pub use certum::u_certum16::uc16;
 pub use certum::u_certum32::uc32;
 pub use certum::u_certum64::uc64;
-pub use certum::u_certum128::uc128;
\ No newline at end of file
+pub use certum::u_certum128::uc128;
+
+// Expose q8-q128 to top-level crate
+pub use quarta::quarta8::q8;
+pub use quarta::quarta16::q16;
+pub use quarta::quarta32::q32;
+pub use quarta::quarta64::q64;
+pub use quarta::quarta128::q128;
+// Expose uq8-uq128 to top-level crate
+pub use quarta::u_quarta8::uq8;
+pub use quarta::u_quarta16::uq16;
+pub use quarta::u_quarta32::uq32;
+pub use quarta::u_quarta64::uq64;
+pub use quarta::u_quarta128::uq128;
+
+// Expose d8-d128 to top-level crate
+pub use dimid::dimid8::d8;
+pub use dimid::dimid16::d16;
+pub use dimid::dimid32::d32;
+pub use dimid::dimid64::d64;
+pub use dimid::dimid128::d128;
+// Expose ud8-ud128 to top-level crate
+pub use dimid::u_dimid8::ud8;
+pub use dimid::u_dimid16::ud16;
+pub use dimid::u_dimid32::ud32;
+pub use dimid::u_dimid64::ud64;
+pub use dimid::u_dimid128::ud128;
+
+// Expose a8-a128 to top-level crate
+pub use acute::acute8::a8;
+pub use acute::acute16::a16;
+pub use acute::acute32::a32;
+pub use acute::acute64::a64;
+pub use acute::acute128::a128;
+// Expose ua8-ua128 to top-level crate
+pub use acute::u_acute8::ua8;
+pub use acute::u_acute16::ua16;
+pub use acute::u_acute32::ua32;
+pub use acute::u_acute64::ua64;
+pub use acute::u_acute128::ua128;
\ No newline at end of file
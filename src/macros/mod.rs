@@ -1,87 +1,520 @@
 #[macro_export]
-/// Negate a certum
-macro_rules! negate {
-    ($target:ident) => {
-        impl Neg for $target {
-            type Output = $target;
-            fn neg(self) -> Self {
-                if self == Self::MIN { return Self::MAX; }
-                Self { bits: !self.bits + 1 }
+/// Define the generic constants and helpers of a signed fixed-point layout for one storage type
+macro_rules! layout_signed {
+    ($target:ident, $uint:ident, $sint:ident) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Bits before the decimal point, including the sign bit
+            pub const DEC: $uint = ($uint::BITS - F) as $uint;
+            /// Bits after the decimal point
+            pub const FRC: $uint = F as $uint;
+            /// Literal bits after the decimal point. 2 ^ FRC
+            pub const FRCPOW: $uint = 1 << F;
+            /// Minimum value in bits
+            pub const MINB: $uint = 1 << ($uint::BITS - 1);
+            /// Maximum value in bits
+            pub const MAXB: $uint = $sint::MAX as $uint;
+            /// Minimum value for this layout. -2 ^ (Integer bits)
+            pub const MIN: Self = Self { bits: Self::MINB };
+            /// Maximum value for this layout. 2 ^ (Integer bits) - GRN
+            pub const MAX: Self = Self { bits: Self::MAXB };
+            /// Minimum value as a 64-bit Float
+            pub const MINF: f64 = $sint::MIN as f64 / f64_pow2(F as i32);
+            /// Maximum value as a 64-bit Float, rounded toward zero so it never exceeds MAX
+            pub const MAXF: f64 = {
+                // Clear the bits below the 53 an f64 holds, so the cast is exact
+                let low = ($uint::BITS - 1).saturating_sub(f64::MANTISSA_DIGITS);
+                ((Self::MAXB >> low) << low) as f64 / f64_pow2(F as i32)
+            };
+            /// Grain - Smallest possible absolute quantity of this type
+            pub const GRN: Self = Self { bits: 0b1 };
+            /// One - The equivalent of integer 1 in bits
+            /// 
+            /// Saturates to MAXB when one is out of range, as for acutes
+            pub const ONE: $uint = if F < $uint::BITS - 1 { 1 << F } else { Self::MAXB };
+
+            /// Get a new value from raw bits
+            pub const fn of(bits: $uint) -> Self {
+                Self { bits }
+            }
+
+            /// Get the sign bit of the current value in the proper location
+            /// 
+            /// 1 = negative, 0 = zero or positive
+            pub fn sign_inverter(&self) -> $uint {
+                if self.bits & Self::MINB == Self::MINB { Self::MINB }
+                else { Self::MAXB }
+            }
+
+            /// Get the binary sign of the current value
+            /// 
+            /// 1 = negative, 0 = zero or positive
+            pub fn bin_sign(&self) -> $uint {
+                if self.bits & Self::MINB == Self::MINB { 1 }
+                else { 0 }
+            }
+
+            /// Get the scalar sign of the current value
+            /// 
+            /// 1 = positive, 0 = zero, -1 = negative
+            pub fn sign(&self) -> i8 {
+                if self.bits == 0 { 0 } // 0 Case
+                else if self.bits & Self::MINB == Self::MINB { -1 } // Match MSB - Negative
+                else { 1 } // Positive
+            }
+
+            /// Return the binary components of the current value
+            /// 
+            /// (Scalar Sign, Integer Component, Fraction Component)
+            pub fn components(&self) -> (i8, $uint, $uint) {
+                let sgn = self.sign(); // Get a binary sign of the value
+                // Cut off sign bit and order integer's smallest component as LSB
+                let int = (self.bits << 1).checked_shr(F + 1).unwrap_or(0);
+                // Order fraction's largest component as MSB
+                let frc = self.bits.checked_shl($uint::BITS - F).unwrap_or(0);
+                (sgn, int, frc)
+            }
+
+            /// Round the top bits of a 64-bit fraction to the storage width, ties rounding up
+            /// 
+            /// Storages of 64 bits or more return the value unchanged
+            pub fn u64_round(val: u64) -> $uint {
+                let half = (1u64 << 63).checked_shr($uint::BITS).unwrap_or(0);
+                (val + half).checked_shr(64 - $uint::BITS.min(64)).unwrap_or(0) as $uint
+            }
+
+            /// Extract the bits as its signed counterpart
+            pub fn as_signed_bits(&self) -> $sint {
+                self.bits as $sint
+            }
+
+            /// Convert to another fraction width of the same storage, rounding to nearest (ties to even) and saturating out-of-range values
+            pub fn rescale<const T: u32>(self) -> $target<$uint, T> {
+                self.rescale_round::<T>(RoundingMode::NearestEven)
+            }
+
+            /// Convert to another fraction width of the same storage, rounding with `mode` and saturating out-of-range values
+            pub fn rescale_round<const T: u32>(self, mode: RoundingMode) -> $target<$uint, T> {
+                let (neg, mag) = self.to_parts();
+                $target::<$uint, T>::from_parts(neg, mag, F, mode)
+            }
+
+            /// Split into the sign and magnitude of the raw bits
+            pub fn to_parts(self) -> (bool, u128) {
+                ((self.bits as $sint) < 0, (self.bits as $sint).unsigned_abs() as u128)
+            }
+
+            /// Build from a sign and a magnitude with `frac` fraction bits, rounding with `mode` and saturating
+            pub fn from_parts(neg: bool, mag: u128, frac: u32, mode: RoundingMode) -> Self {
+                let (neg, wide) = rescale_parts(neg, mag, frac, F, u128::MAX, u128::MAX, mode);
+                let max = if neg { Self::MINB as u128 } else { Self::MAXB as u128 };
+                raise_rescaled(mag, frac, F, wide, max);
+                let bits = wide.min(max) as $uint;
+                Self { bits: if neg { bits.wrapping_neg() } else { bits } }
+            }
+
+            /// Build from a sign and a magnitude with `frac` fraction bits, rounding to nearest (ties to even)
+            /// 
+            /// Fails instead of saturating, and with PrecisionLoss on any rounding when `strict` is set
+            pub(crate) fn try_from_parts(neg: bool, mag: u128, frac: u32, strict: bool) -> Result<Self, CertumError> {
+                let (_, round) = rescale_parts(neg, mag, frac, F, u128::MAX, u128::MAX, RoundingMode::NearestEven);
+                let max = if neg { Self::MINB as u128 } else { Self::MAXB as u128 };
+                if round > max { return Err(CertumError::Overflow); }
+                if round == 0 && mag != 0 { return Err(CertumError::Underflow); }
+                if strict && !is_exact(mag, frac, F) { return Err(CertumError::PrecisionLoss); }
+                let bits = round as $uint;
+                Ok(Self { bits: if neg { bits.wrapping_neg() } else { bits } })
+            }
+
+            /// Round an exact sign and u256 magnitude with `frac` fraction bits to the nearest value (ties to even),
+            /// saturating at MIN/MAX and raising the matching status flags
+            pub fn from_exact(neg: bool, mag: u256, frac: u32) -> Self {
+                let max = if neg { Self::MINB as u128 } else { Self::MAXB as u128 };
+                match wide::round(mag, wide::FRAC as i64 - frac as i64, F, neg, RoundingMode::NearestEven) {
+                    Some(round) if round <= max => {
+                        if frac > F && mag.trailing_zeros() < frac - F { raise_rounded(round == 0); }
+                        let bits = round as $uint;
+                        Self { bits: if neg { bits.wrapping_neg() } else { bits } }
+                    }
+                    _ => Self::saturated(neg),
+                }
+            }
+
+            /// Convert from the raw bits of an IEEE float with `man` mantissa bits and `exp` exponent bits,
+            /// failing instead of saturating
+            pub(crate) fn try_from_float_bits(bits: u128, man: u32, exp: u32, strict: bool) -> Result<Self, CertumError> {
+                match float_to_parts(bits, man, exp) {
+                    None => Err(CertumError::NaN),
+                    // No float equals u128::MAX exactly, so this is always a saturated magnitude
+                    Some((_, u128::MAX, 0)) => Err(CertumError::Overflow),
+                    Some((neg, mag, frac)) => Self::try_from_parts(neg, mag, frac, strict),
+                }
+            }
+
+            /// Convert from a 64-bit float, rounding to nearest (ties to even) and failing with Overflow, Underflow or NaN instead of saturating
+            /// 
+            /// `TryFrom<f64>` cannot exist next to the saturating `From<f64>`, so this stands in for it without importing `FromChecked`
+            pub fn try_from_f64(val: f64) -> Result<Self, CertumError> {
+                Self::try_from_float_bits(val.to_bits() as u128, 52, 11, false)
+            }
+
+            /// Convert from a 32-bit float, rounding to nearest (ties to even) and failing with Overflow, Underflow or NaN instead of saturating
+            pub fn try_from_f32(val: f32) -> Result<Self, CertumError> {
+                Self::try_from_float_bits(val.to_bits() as u128, 23, 8, false)
+            }
+
+            /// Convert through `FromChecked::from_checked`, for example from another layout, without importing the trait
+            pub fn try_convert<T>(val: T) -> Result<Self, CertumError> where Self: FromChecked<T> {
+                <Self as FromChecked<T>>::from_checked(val)
+            }
+
+            /// Convert through `FromChecked::from_strict`, also failing with PrecisionLoss, without importing the trait
+            pub fn strict_convert<T>(val: T) -> Result<Self, CertumError> where Self: FromChecked<T> {
+                <Self as FromChecked<T>>::from_strict(val)
+            }
+
+            /// Round to the raw bits of an IEEE float with `man` mantissa bits and `exp` exponent bits, ties to even
+            pub(crate) fn to_float_bits(self, man: u32, exp: u32) -> u128 {
+                let (neg, mag) = self.to_parts();
+                parts_to_float(neg, mag, F, man, exp)
+            }
+
+            /// Convert from the raw bits of an IEEE float with `man` mantissa bits and `exp` exponent bits,
+            /// rounding to nearest (ties to even) and saturating at MIN/MAX. NaN gives zero, flagged as inexact
+            pub(crate) fn from_float_bits(bits: u128, man: u32, exp: u32) -> Self {
+                match float_to_parts(bits, man, exp) {
+                    None => {
+                        raise(StatusFlags::INEXACT);
+                        Self { bits: 0 }
+                    }
+                    Some((neg, u128::MAX, 0)) => {
+                        raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                        Self::from_parts(neg, u128::MAX, 0, RoundingMode::NearestEven)
+                    }
+                    Some((neg, mag, frac)) => Self::from_parts(neg, mag, frac, RoundingMode::NearestEven),
+                }
+            }
+
+            /// Round a sign and a working precision magnitude scaled by 2^exp to the nearest value
+            /// 
+            /// Returns None when it is out of range
+            pub(crate) fn checked_from_wide(neg: bool, val: u256, exp: i64) -> Option<Self> {
+                let mag = wide::round(val, exp, F, neg, RoundingMode::NearestEven)?;
+                let max = if neg { Self::MINB as u128 } else { Self::MAXB as u128 };
+                if mag > max { return None; }
+                let bits = mag as $uint;
+                Some(Self { bits: if neg { bits.wrapping_neg() } else { bits } })
+            }
+
+            /// Round a sign and a working precision magnitude scaled by 2^exp to the nearest value, saturating at MIN/MAX.
+            /// 
+            /// A nonzero magnitude that rounds to zero raises the underflow flag
+            pub(crate) fn from_wide(neg: bool, val: u256, exp: i64) -> Self {
+                match Self::checked_from_wide(neg, val, exp) {
+                    Some(res) => {
+                        if res.bits == 0 && val != u256::MIN { raise_rounded(true); }
+                        res
+                    }
+                    None => Self::saturated(neg),
+                }
+            }
+
+            /// MIN when `neg` is set and MAX otherwise, raising the overflow flag
+            pub(crate) fn saturated(neg: bool) -> Self {
+                raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                if neg { Self::MIN } else { Self::MAX }
+            }
+
+            /// Print line to console with a name and bits
+            pub fn log_bits(&self) {
+                println!("0b{:0width$b}", self.bits, width = $uint::BITS as usize);
+            }
+
+            /// Print line to console with a name and hexadecimal bits
+            pub fn log_hex(&self) {
+                println!("0x{:0width$X}", self.bits, width = $uint::BITS as usize / 4);
+            }
+
+            /// Print line to console with the exact decimal value
+            pub fn log_value(&self) {
+                println!("{}", self);
             }
         }
     }
 }
 
 #[macro_export]
-/// Convert between two certum variants by directly replacing bits
-macro_rules! from_direct {
-    ($source:ident, $target:ident) => {
-        impl From<$source> for $target {
-            fn from(val: $source) -> Self {
-                $target { bits: val.bits }
+/// Define the generic constants and helpers of an unsigned fixed-point layout for one storage type
+macro_rules! layout_unsigned {
+    ($target:ident, $uint:ident) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Bits before the decimal point
+            pub const DEC: $uint = ($uint::BITS - F) as $uint;
+            /// Bits after the decimal point
+            pub const FRC: $uint = F as $uint;
+            /// Literal bits after the decimal point. 2 ^ FRC
+            /// 
+            /// Not available when every bit is a fraction bit
+            pub const FRCPOW: $uint = 1 << F;
+            /// Minimum value for this layout. Always zero
+            pub const MIN: Self = Self { bits: 0 };
+            /// Maximum value for this layout. 2 ^ (Integer bits) - GRN
+            pub const MAX: Self = Self { bits: $uint::MAX };
+            /// Minimum value as a 64-bit Float
+            pub const MINF: f64 = 0f64;
+            /// Maximum value as a 64-bit Float, rounded toward zero so it never exceeds MAX
+            pub const MAXF: f64 = {
+                // Clear the bits below the 53 an f64 holds, so the cast is exact
+                let low = $uint::BITS.saturating_sub(f64::MANTISSA_DIGITS);
+                (($uint::MAX >> low) << low) as f64 / f64_pow2(F as i32)
+            };
+            /// Grain - Smallest possible absolute quantity of this type
+            pub const GRN: Self = Self { bits: 0b1 };
+            /// One - The equivalent of integer 1 in bits
+            /// 
+            /// Saturates to the bits of MAX when every bit is a fraction bit, as for unsigned acutes
+            pub const ONE: $uint = if F < $uint::BITS { 1 << F } else { $uint::MAX };
+
+            /// Get a new value from raw bits
+            pub const fn of(bits: $uint) -> Self {
+                Self { bits }
+            }
+
+            /// Return the binary components of the current value
+            /// 
+            /// (Integer Component, Fraction Component)
+            pub fn components(&self) -> ($uint, $uint) {
+                // Order ints smallest component as LSB
+                let int = self.bits.checked_shr(F).unwrap_or(0);
+                // Order fraction's largest component as MSB
+                let frc = self.bits.checked_shl($uint::BITS - F).unwrap_or(0);
+                (int, frc)
+            }
+
+            /// Round the top bits of a 64-bit fraction to the storage width, ties rounding up
+            /// 
+            /// Storages of 64 bits or more return the value unchanged
+            pub fn u64_round(val: u64) -> $uint {
+                let half = (1u64 << 63).checked_shr($uint::BITS).unwrap_or(0);
+                (val + half).checked_shr(64 - $uint::BITS.min(64)).unwrap_or(0) as $uint
+            }
+
+            /// Convert to another fraction width of the same storage, rounding to nearest (ties to even) and saturating out-of-range values
+            pub fn rescale<const T: u32>(self) -> $target<$uint, T> {
+                self.rescale_round::<T>(RoundingMode::NearestEven)
+            }
+
+            /// Convert to another fraction width of the same storage, rounding with `mode` and saturating out-of-range values
+            pub fn rescale_round<const T: u32>(self, mode: RoundingMode) -> $target<$uint, T> {
+                $target::<$uint, T>::from_parts(false, self.bits as u128, F, mode)
+            }
+
+            /// Split into the sign and magnitude of the raw bits
+            pub fn to_parts(self) -> (bool, u128) {
+                (false, self.bits as u128)
+            }
+
+            /// Build from a sign and a magnitude with `frac` fraction bits, rounding with `mode` and saturating
+            pub fn from_parts(neg: bool, mag: u128, frac: u32, mode: RoundingMode) -> Self {
+                let (neg, wide) = rescale_parts(neg, mag, frac, F, u128::MAX, u128::MAX, mode);
+                let max = if neg { 0 } else { $uint::MAX as u128 };
+                raise_rescaled(mag, frac, F, wide, max);
+                let bits = wide.min(max) as $uint;
+                Self { bits: if neg { bits.wrapping_neg() } else { bits } }
+            }
+
+            /// Build from a sign and a magnitude with `frac` fraction bits, rounding to nearest (ties to even)
+            /// 
+            /// Fails instead of saturating, and with PrecisionLoss on any rounding when `strict` is set
+            pub(crate) fn try_from_parts(neg: bool, mag: u128, frac: u32, strict: bool) -> Result<Self, CertumError> {
+                let (_, round) = rescale_parts(neg, mag, frac, F, u128::MAX, u128::MAX, RoundingMode::NearestEven);
+                let max = if neg { 0 } else { $uint::MAX as u128 };
+                if round > max { return Err(CertumError::Overflow); }
+                if round == 0 && mag != 0 { return Err(CertumError::Underflow); }
+                if strict && !is_exact(mag, frac, F) { return Err(CertumError::PrecisionLoss); }
+                let bits = round as $uint;
+                Ok(Self { bits: if neg { bits.wrapping_neg() } else { bits } })
+            }
+
+            /// Round an exact sign and u256 magnitude with `frac` fraction bits to the nearest value (ties to even),
+            /// saturating at MIN/MAX and raising the matching status flags
+            pub fn from_exact(neg: bool, mag: u256, frac: u32) -> Self {
+                let max = if neg { 0 } else { $uint::MAX as u128 };
+                match wide::round(mag, wide::FRAC as i64 - frac as i64, F, neg, RoundingMode::NearestEven) {
+                    Some(round) if round <= max => {
+                        if frac > F && mag.trailing_zeros() < frac - F { raise_rounded(round == 0); }
+                        let bits = round as $uint;
+                        Self { bits: if neg { bits.wrapping_neg() } else { bits } }
+                    }
+                    _ => Self::saturated(neg),
+                }
+            }
+
+            /// Convert from the raw bits of an IEEE float with `man` mantissa bits and `exp` exponent bits,
+            /// failing instead of saturating
+            pub(crate) fn try_from_float_bits(bits: u128, man: u32, exp: u32, strict: bool) -> Result<Self, CertumError> {
+                match float_to_parts(bits, man, exp) {
+                    None => Err(CertumError::NaN),
+                    // No float equals u128::MAX exactly, so this is always a saturated magnitude
+                    Some((_, u128::MAX, 0)) => Err(CertumError::Overflow),
+                    Some((neg, mag, frac)) => Self::try_from_parts(neg, mag, frac, strict),
+                }
+            }
+
+            /// Convert from a 64-bit float, rounding to nearest (ties to even) and failing with Overflow, Underflow or NaN instead of saturating
+            /// 
+            /// `TryFrom<f64>` cannot exist next to the saturating `From<f64>`, so this stands in for it without importing `FromChecked`
+            pub fn try_from_f64(val: f64) -> Result<Self, CertumError> {
+                Self::try_from_float_bits(val.to_bits() as u128, 52, 11, false)
+            }
+
+            /// Convert from a 32-bit float, rounding to nearest (ties to even) and failing with Overflow, Underflow or NaN instead of saturating
+            pub fn try_from_f32(val: f32) -> Result<Self, CertumError> {
+                Self::try_from_float_bits(val.to_bits() as u128, 23, 8, false)
+            }
+
+            /// Convert through `FromChecked::from_checked`, for example from another layout, without importing the trait
+            pub fn try_convert<T>(val: T) -> Result<Self, CertumError> where Self: FromChecked<T> {
+                <Self as FromChecked<T>>::from_checked(val)
+            }
+
+            /// Convert through `FromChecked::from_strict`, also failing with PrecisionLoss, without importing the trait
+            pub fn strict_convert<T>(val: T) -> Result<Self, CertumError> where Self: FromChecked<T> {
+                <Self as FromChecked<T>>::from_strict(val)
+            }
+
+            /// Round to the raw bits of an IEEE float with `man` mantissa bits and `exp` exponent bits, ties to even
+            pub(crate) fn to_float_bits(self, man: u32, exp: u32) -> u128 {
+                let (neg, mag) = self.to_parts();
+                parts_to_float(neg, mag, F, man, exp)
+            }
+
+            /// Convert from the raw bits of an IEEE float with `man` mantissa bits and `exp` exponent bits,
+            /// rounding to nearest (ties to even) and saturating at MIN/MAX. NaN gives zero, flagged as inexact
+            pub(crate) fn from_float_bits(bits: u128, man: u32, exp: u32) -> Self {
+                match float_to_parts(bits, man, exp) {
+                    None => {
+                        raise(StatusFlags::INEXACT);
+                        Self { bits: 0 }
+                    }
+                    Some((neg, u128::MAX, 0)) => {
+                        raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                        Self::from_parts(neg, u128::MAX, 0, RoundingMode::NearestEven)
+                    }
+                    Some((neg, mag, frac)) => Self::from_parts(neg, mag, frac, RoundingMode::NearestEven),
+                }
+            }
+
+            /// Round a sign and a working precision magnitude scaled by 2^exp to the nearest value
+            /// 
+            /// Returns None when it is out of range, which includes every negative value that does not round to zero
+            pub(crate) fn checked_from_wide(neg: bool, val: u256, exp: i64) -> Option<Self> {
+                let mag = wide::round(val, exp, F, neg, RoundingMode::NearestEven)?;
+                let max = if neg { 0 } else { $uint::MAX as u128 };
+                if mag > max { return None; }
+                Some(Self { bits: mag as $uint })
+            }
+
+            /// Round a sign and a working precision magnitude scaled by 2^exp to the nearest value, saturating at MIN/MAX.
+            /// 
+            /// A nonzero magnitude that rounds to zero raises the underflow flag
+            pub(crate) fn from_wide(neg: bool, val: u256, exp: i64) -> Self {
+                match Self::checked_from_wide(neg, val, exp) {
+                    Some(res) => {
+                        if res.bits == 0 && val != u256::MIN { raise_rounded(true); }
+                        res
+                    }
+                    None => Self::saturated(neg),
+                }
+            }
+
+            /// MIN when `neg` is set and MAX otherwise, raising the overflow flag
+            pub(crate) fn saturated(neg: bool) -> Self {
+                raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                if neg { Self::MIN } else { Self::MAX }
+            }
+
+            /// Print line to console with a name and bits
+            pub fn log_bits(&self) {
+                println!("0b{:0width$b}", self.bits, width = $uint::BITS as usize);
+            }
+
+            /// Print line to console with a name and hexadecimal bits
+            pub fn log_hex(&self) {
+                println!("0x{:0width$X}", self.bits, width = $uint::BITS as usize / 4);
+            }
+
+            /// Print line to console with the exact decimal value
+            pub fn log_value(&self) {
+                println!("{}", self);
             }
         }
     }
 }
 
 #[macro_export]
-/// Convert between two certum variants through a left shift then a cast
-macro_rules! from_left_shift {
-    ($source:ident, $target:ident, $cast:ty, $bits:expr, $shift:expr) => {
-        impl From<$source> for $target {
-            fn from(val: $source) -> Self {
-                if val == $source::MIN { return $target::MIN; }
-                if val == $source::MAX { return $target::MAX; }
-                $target { bits: (val.bits as $cast) << ($bits - $shift) }
+/// Negate a certum
+macro_rules! negate {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> Neg for $target<$uint, F> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                if self == Self::MIN { return Self::saturated(false); }
+                Self { bits: (!self.bits).wrapping_add(1) }
             }
         }
     }
 }
 
 #[macro_export]
-/// Convert between two certum variants through a cast then a right shift
-macro_rules! from_right_shift {
-    ($source:ident, $target:ident, $cast:ty, $bits:expr, $shift:expr) => {
-        impl From<$source> for $target {
-            fn from(val: $source) -> Self {
-                if val == $source::MIN { return $target::MIN; }
-                if val == $source::MAX { return $target::MAX; }
-                $target { bits: (val.bits >> ($bits - $shift)) as $cast }
+/// Convert between the signed and unsigned variant of a layout by directly replacing bits
+macro_rules! from_direct {
+    ($source:ident, $target:ident, $uint:ty) => {
+        impl<const F: u32> From<$source<$uint, F>> for $target<$uint, F> {
+            fn from(val: $source<$uint, F>) -> Self {
+                Self { bits: val.bits }
             }
         }
     }
 }
 
 #[macro_export]
-/// Convert between two certum variants through a cast then a left shift
-macro_rules! from_left_shift_signed {
-    ($source:ident, $target:ident, $cast:ty, $to_bits:expr, $from_bits:expr, $shift:expr) => {
-        impl From<$source> for $target {
-            fn from(val: $source) -> Self {
-                if val == $source::MIN { return $target::MIN; }
-                if val == $source::MAX { return $target::MAX; }
-                let sign = val.sign();
-                if sign == -1i8 {
-                    -$target { bits: (((-val).bits & $source::MAXB) as $cast) << ($to_bits - $from_bits - $shift) }
-                } else {
-                    $target { bits: ((val.bits & $source::MAXB) as $cast) << ($to_bits - $from_bits - $shift) }
-                }
+/// Convert an unsigned type to another storage type, moving the bits to the target fraction width.
+/// 
+/// Values that fit are kept, rounding only the fraction bits the target cannot hold. Out-of-range values saturate to MIN/MAX
+macro_rules! from_storage {
+    ($target:ident, $from:ty, $to:ty) => {
+        impl<const A: u32, const B: u32> From<$target<$from, A>> for $target<$to, B> {
+            /// Convert, rounding to nearest (ties to even)
+            fn from(val: $target<$from, A>) -> Self {
+                Self::from_round(val, RoundingMode::NearestEven)
+            }
+        }
+
+        impl<const A: u32, const B: u32> FromRound<$target<$from, A>> for $target<$to, B> {
+            fn from_round(val: $target<$from, A>, mode: RoundingMode) -> Self {
+                Self::from_parts(false, val.bits as u128, A, mode)
             }
         }
     }
 }
 
 #[macro_export]
-/// Convert between two certum variants through a right shift then a cast
-macro_rules! from_right_shift_signed {
-    ($source:ident, $target:ident, $cast:ty, $to_bits:expr, $from_bits:expr, $shift:expr) => {
-        impl From<$source> for $target {
-            fn from(val: $source) -> Self {
-                if val == $source::MIN { return $target::MIN; }
-                if val == $source::MAX { return $target::MAX; }
-                let sign = ((val.sign_inverter() >> ($from_bits - $to_bits - $shift)) as $cast) ^ $target::MINB;
-                let bit = sign & $target::MINB;
-                $target { bits: (((val.bits >> ($from_bits - $to_bits - $shift)) as $cast) & $target::MAXB) | bit }
+/// Convert a signed type to another storage type, moving the bits to the target fraction width.
+/// 
+/// Values that fit are kept, rounding only the fraction bits the target cannot hold. Out-of-range values saturate to MIN/MAX
+macro_rules! from_storage_signed {
+    ($target:ident, $from:ty, $to:ty) => {
+        impl<const A: u32, const B: u32> From<$target<$from, A>> for $target<$to, B> {
+            /// Convert, rounding to nearest (ties to even)
+            fn from(val: $target<$from, A>) -> Self {
+                Self::from_round(val, RoundingMode::NearestEven)
+            }
+        }
+
+        impl<const A: u32, const B: u32> FromRound<$target<$from, A>> for $target<$to, B> {
+            fn from_round(val: $target<$from, A>, mode: RoundingMode) -> Self {
+                let (neg, mag) = val.to_parts();
+                Self::from_parts(neg, mag, A, mode)
             }
         }
     }
@@ -90,8 +523,8 @@ macro_rules! from_right_shift_signed {
 #[macro_export]
 /// Greater-Than / Less-Than / Equal-To Checks
 macro_rules! comparison_solo_signed {
-    ($target:ident, $sint:ident) => {
-        impl Ord for $target {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> Ord for $target<$uint, F> {
             fn cmp(&self, other: &Self) -> Ordering {
                 let self_signed = self.as_signed_bits();
                 let other_signed = other.as_signed_bits();
@@ -99,7 +532,7 @@ macro_rules! comparison_solo_signed {
             }
         }
 
-        impl PartialOrd for $target {
+        impl<const F: u32> PartialOrd for $target<$uint, F> {
             fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
                 Some(self.cmp(other))
             }
@@ -110,14 +543,14 @@ macro_rules! comparison_solo_signed {
 #[macro_export]
 /// Greater-Than / Less-Than / Equal-To Checks
 macro_rules! comparison_solo_unsigned {
-    ($target:ident) => {
-        impl Ord for $target {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> Ord for $target<$uint, F> {
             fn cmp(&self, other: &Self) -> Ordering {
                 self.bits.cmp(&other.bits)
             }
         }
 
-        impl PartialOrd for $target {
+        impl<const F: u32> PartialOrd for $target<$uint, F> {
             fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
                 Some(self.cmp(other))
             }
@@ -128,51 +561,123 @@ macro_rules! comparison_solo_unsigned {
 #[macro_export]
 /// Equivalency Checks
 macro_rules! equivalent_solo {
-    ($target:ident) => {
-        impl PartialEq for $target {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> PartialEq for $target<$uint, F> {
             fn eq(&self, other: &Self) -> bool {
                 self.bits == other.bits
             }
-
-            fn ne(&self, other: &Self) -> bool {
-                self.bits != other.bits
-            }
         }
 
-        impl Eq for $target { }
+        impl<const F: u32> Eq for $target<$uint, F> { }
     }
 }
 
 #[macro_export]
-/// Equivalency Checks
+/// Exact Equivalency and Greater-Than / Less-Than Checks between two different layouts
 macro_rules! equivalent_other {
-    ($target:ident, $other:ident) => {
-        impl PartialEq<$other> for $target {
-            fn eq(&self, other: &Self) -> bool {
-                self.bits == ($target::from(other)).bits
+    ($target:ident, $tuint:ty, $other:ident, $ouint:ty) => {
+        impl<const A: u32, const B: u32> PartialEq<$other<$ouint, B>> for $target<$tuint, A> {
+            fn eq(&self, other: &$other<$ouint, B>) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
             }
+        }
+
+        impl<const A: u32, const B: u32> PartialEq<$target<$tuint, A>> for $other<$ouint, B> {
+            fn eq(&self, other: &$target<$tuint, A>) -> bool {
+                other == self
+            }
+        }
 
-            fn ne(&self, other: &Self) -> bool {
-                self.bits != ($target::from(other)).bits
+        impl<const A: u32, const B: u32> PartialOrd<$other<$ouint, B>> for $target<$tuint, A> {
+            fn partial_cmp(&self, other: &$other<$ouint, B>) -> Option<Ordering> {
+                let (lneg, lmag) = self.to_parts();
+                let (rneg, rmag) = other.to_parts();
+                Some(cmp_parts((lneg, lmag, A), (rneg, rmag, B)))
             }
         }
 
-        impl Eq<$other> for $target { }
+        impl<const A: u32, const B: u32> PartialOrd<$target<$tuint, A>> for $other<$ouint, B> {
+            fn partial_cmp(&self, other: &$target<$tuint, A>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
     }
 }
 
+#[macro_export]
+/// Addition, Subtraction and Multiplication between two different layouts
+/// 
+/// The result is always the `$wide` layout. It is computed exactly from both operands and rounded once
+/// (to nearest, ties to even), so only the result saturates
+macro_rules! mixed_ops {
+    ($wide:ident, $wuint:ty, $narrow:ident, $nuint:ty) => {
+        impl<const A: u32, const B: u32> Add<$narrow<$nuint, B>> for $wide<$wuint, A> {
+            type Output = Self;
+            fn add(self, rhs: $narrow<$nuint, B>) -> Self {
+                let (lneg, lmag) = self.to_parts();
+                let (rneg, rmag) = rhs.to_parts();
+                let (neg, mag, frac) = add_parts((lneg, lmag, A), (rneg, rmag, B));
+                Self::from_exact(neg, mag, frac)
+            }
+        }
+
+        impl<const A: u32, const B: u32> Add<$wide<$wuint, A>> for $narrow<$nuint, B> {
+            type Output = $wide<$wuint, A>;
+            fn add(self, rhs: $wide<$wuint, A>) -> $wide<$wuint, A> {
+                rhs + self
+            }
+        }
+
+        impl<const A: u32, const B: u32> Sub<$narrow<$nuint, B>> for $wide<$wuint, A> {
+            type Output = Self;
+            fn sub(self, rhs: $narrow<$nuint, B>) -> Self {
+                let (lneg, lmag) = self.to_parts();
+                let (rneg, rmag) = rhs.to_parts();
+                let (neg, mag, frac) = add_parts((lneg, lmag, A), (!rneg, rmag, B));
+                Self::from_exact(neg, mag, frac)
+            }
+        }
+
+        impl<const A: u32, const B: u32> Sub<$wide<$wuint, A>> for $narrow<$nuint, B> {
+            type Output = $wide<$wuint, A>;
+            fn sub(self, rhs: $wide<$wuint, A>) -> $wide<$wuint, A> {
+                let (lneg, lmag) = self.to_parts();
+                let (rneg, rmag) = rhs.to_parts();
+                let (neg, mag, frac) = add_parts((lneg, lmag, B), (!rneg, rmag, A));
+                <$wide<$wuint, A>>::from_exact(neg, mag, frac)
+            }
+        }
+
+        impl<const A: u32, const B: u32> Mul<$narrow<$nuint, B>> for $wide<$wuint, A> {
+            type Output = Self;
+            fn mul(self, rhs: $narrow<$nuint, B>) -> Self {
+                let (lneg, lmag) = self.to_parts();
+                let (rneg, rmag) = rhs.to_parts();
+                Self::from_exact(lneg ^ rneg, u256::from_mul(lmag, rmag), A + B)
+            }
+        }
+
+        impl<const A: u32, const B: u32> Mul<$wide<$wuint, A>> for $narrow<$nuint, B> {
+            type Output = $wide<$wuint, A>;
+            fn mul(self, rhs: $wide<$wuint, A>) -> $wide<$wuint, A> {
+                rhs * self
+            }
+        }
+    };
+}
+
 #[macro_export]
 /// Equivalent Bit Checks
 macro_rules! equivalent_bits {
-    ($target:ident, $uint:ident) => {
-        impl PartialEq<$uint> for $target {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> PartialEq<$uint> for $target<$uint, F> {
             fn eq(&self, uint: &$uint) -> bool {
                 self.bits == *uint
             }
         }
 
-        impl PartialEq<$target> for $uint {
-            fn eq(&self, target: &$target) -> bool {
+        impl<const F: u32> PartialEq<$target<$uint, F>> for $uint {
+            fn eq(&self, target: &$target<$uint, F>) -> bool {
                 *self == (*target).bits
             }
         }
@@ -183,15 +688,15 @@ macro_rules! equivalent_bits {
 /// Addition for types
 macro_rules! add_same {
     ($target:ident, $uint:ty, $sint:ty) => {
-        impl Add for $target {
-            type Output = $target;
+        impl<const F: u32> Add for $target<$uint, F> {
+            type Output = Self;
+            /// Add two values, saturating at MIN/MAX
             fn add(self, rhs: Self) -> Self {
-                let bits = <$sint>::saturating_add(self.bits as $sint, rhs.bits as $sint);
-                $target { bits: bits as $uint }
+                self.saturating_add(rhs)
             }
         }
 
-        impl AddAssign for $target {
+        impl<const F: u32> AddAssign for $target<$uint, F> {
             fn add_assign(&mut self, rhs: Self) {
                 *self = *self + rhs
             }
@@ -203,15 +708,15 @@ macro_rules! add_same {
 /// Subtraction for types
 macro_rules! sub_same {
     ($target:ident, $uint:ty, $sint:ty) => {
-        impl Sub for $target {
-            type Output = $target;
+        impl<const F: u32> Sub for $target<$uint, F> {
+            type Output = Self;
+            /// Subtract two values, saturating at MIN/MAX
             fn sub(self, rhs: Self) -> Self {
-                let bits = <$sint>::saturating_sub(self.bits as $sint, rhs.bits as $sint);
-                $target { bits: bits as $uint }
+                self.saturating_sub(rhs)
             }
         }
 
-        impl SubAssign for $target {
+        impl<const F: u32> SubAssign for $target<$uint, F> {
             fn sub_assign(&mut self, rhs: Self) {
                 *self = *self - rhs
             }
@@ -220,133 +725,1773 @@ macro_rules! sub_same {
 }
 
 #[macro_export]
-/// Multiplication for signed types
-macro_rules! mul_same_signed {
-    ($target:ident, $uint:ty, $duint:ty) => {
-        impl Mul for $target {
-            type Output = $target;
-            fn mul(self, rhs: Self) -> Self {
-                let self_sign = self.bin_sign(); 
-                let rhs_sign = rhs.bin_sign(); 
-                let signed_self;
-                let signed_rhs;
-                if self_sign == 1 {
-                    signed_self = (-self).bits as $duint;
-                } else {
-                    signed_self = self.bits as $duint;
-                }
-                if rhs_sign == 1 {
-                    signed_rhs = (-rhs).bits as $duint;
-                } else {
-                    signed_rhs = rhs.bits as $duint;
-                }
-                let bits = <$duint>::saturating_mul(signed_self, signed_rhs) >> $target::FRC;
-                if ((self_sign == 1) & (rhs_sign == 0)) | ((self_sign == 0) & (rhs_sign == 1)) {
-                    -$target { bits: (bits as $uint) }
-                } else {
-                    $target { bits: (bits as $uint) }
-                }
+/// Checked, wrapping, overflowing and saturating arithmetic for signed types
+/// 
+/// Built on the overflowing_mul and overflowing_div of each width
+macro_rules! arith_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Add, returning the sum wrapped to the bit width and whether it overflowed
+            pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (bits, overflow) = <$sint>::overflowing_add(self.bits as $sint, rhs.bits as $sint);
+                (Self { bits: bits as $uint }, overflow)
             }
-        }
 
-        impl MulAssign for $target {
-            fn mul_assign(&mut self, rhs: Self) {
-                *self = *self * rhs
+            /// Subtract, returning the difference wrapped to the bit width and whether it overflowed
+            pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (bits, overflow) = <$sint>::overflowing_sub(self.bits as $sint, rhs.bits as $sint);
+                (Self { bits: bits as $uint }, overflow)
             }
-        }
-    }
-}
 
-#[macro_export]
-/// Multiplication for signed 128-bit types
-macro_rules! mul_same_signed_128 {
-    ($target:ident, $uint:ident, $duint:ident) => {
-        impl Mul for $target {
-            type Output = $target;
-            fn mul(self, rhs: Self) -> Self {
-                let self_sign = self.bin_sign(); 
-                let rhs_sign = rhs.bin_sign(); 
-                let signed_self;
-                let signed_rhs;
-                if self_sign == 1 {
-                    signed_self = -self;
-                } else {
-                    signed_self = self;
+            /// Add, returning None on overflow
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_add(rhs) {
+                    (res, false) => Some(res),
+                    _ => None,
                 }
-                if rhs_sign == 1 {
-                    signed_rhs = -rhs;
-                } else {
-                    signed_rhs = rhs;
+            }
+
+            /// Subtract, returning None on overflow
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_sub(rhs) {
+                    (res, false) => Some(res),
+                    _ => None,
                 }
-                let bits = <$duint>::from_mul(signed_self.bits, signed_rhs.bits) >> $target::FRC;
-                if ((self_sign == 1) & (rhs_sign == 0)) | ((self_sign == 0) & (rhs_sign == 1)) {
-                    -$target { bits: $uint::from(bits) }
-                } else {
-                    $target { bits: $uint::from(bits) }
+            }
+
+            /// Multiply, returning None on overflow
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_mul(rhs) {
+                    (res, false) => Some(res),
+                    _ => None,
                 }
             }
-        }
 
-        impl MulAssign for $target {
-            fn mul_assign(&mut self, rhs: Self) {
-                *self = *self * rhs
+            /// Divide, returning None when dividing by zero or on overflow
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                if rhs.bits == 0 { return None; }
+                match self.overflowing_div(rhs) {
+                    (res, false) => Some(res),
+                    _ => None,
+                }
             }
-        }
-    }
-}
 
-#[macro_export]
-/// Multiplication for unsigned types
-macro_rules! mul_same_unsigned {
-    ($target:ident, $uint:ty, $duint:ty) => {
-        impl Mul for $target {
-            type Output = $target;
-            fn mul(self, rhs: Self) -> Self {
-                let signed_self = self.bits as $duint;
-                let signed_rhs = rhs.bits as $duint;
-                let bits = <$duint>::saturating_mul(signed_self, signed_rhs) >> $target::FRC;
-                $target { bits: (bits as $uint) }
+            /// Add, wrapping around at the boundary of the type
+            pub fn wrapping_add(self, rhs: Self) -> Self {
+                self.overflowing_add(rhs).0
             }
-        }
 
-        impl MulAssign for $target {
-            fn mul_assign(&mut self, rhs: Self) {
-                *self = *self * rhs
+            /// Subtract, wrapping around at the boundary of the type
+            pub fn wrapping_sub(self, rhs: Self) -> Self {
+                self.overflowing_sub(rhs).0
             }
-        }
-    }
-}
 
-#[macro_export]
-/// Multiplication for unsigned 128-bit types
-macro_rules! mul_same_unsigned_128 {
-    ($target:ident, $uint:ident, $duint:ident) => {
-        impl Mul for $target {
-            type Output = $target;
-            fn mul(self, rhs: Self) -> Self {
-                let bits = <$duint>::from_mul(self.bits, rhs.bits) >> $target::FRC;
-                $target { bits: $uint::from(bits) }
+            /// Multiply, wrapping around at the boundary of the type
+            pub fn wrapping_mul(self, rhs: Self) -> Self {
+                self.overflowing_mul(rhs).0
             }
-        }
 
-        impl MulAssign for $target {
-            fn mul_assign(&mut self, rhs: Self) {
-                *self = *self * rhs
+            /// Divide, wrapping around at the boundary of the type. Panics when dividing by zero
+            pub fn wrapping_div(self, rhs: Self) -> Self {
+                self.overflowing_div(rhs).0
             }
-        }
-    }
-}
 
-#[macro_export]
-/// Convert quire to 128-bit types
-macro_rules! from_u256 {
-    ($target:ident) => {
-        impl From<u256> for $target {
-            /// Convert from a quire to a 128-bit type.
+            /// Add, saturating at MIN/MAX
             /// 
-            /// Equivalent to using `as` to cast from bigger int to a smaller int
-            fn from(val: u256) -> Self {
-                $target::from(val.bits[1])
+            /// Raises the overflow and inexact flags when saturating
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                match self.overflowing_add(rhs) {
+                    (res, false) => res,
+                    _ => {
+                        raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                        if rhs.bin_sign() == 1 { Self::MIN } else { Self::MAX }
+                    }
+                }
+            }
+
+            /// Subtract, saturating at MIN/MAX
+            /// 
+            /// Raises the overflow and inexact flags when saturating
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                match self.overflowing_sub(rhs) {
+                    (res, false) => res,
+                    _ => {
+                        raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                        if rhs.bin_sign() == 1 { Self::MAX } else { Self::MIN }
+                    }
+                }
+            }
+
+            /// Multiply, saturating at MIN/MAX
+            /// 
+            /// Raises the overflow and inexact flags when saturating, the inexact flag when rounding,
+            /// and the underflow flag when a nonzero product rounds to zero
+            pub fn saturating_mul(self, rhs: Self) -> Self {
+                match self.overflowing_mul(rhs) {
+                    (res, false) => {
+                        // The product is exact when rounding down and up agree
+                        if recording() && self.overflowing_mul_round(rhs, RoundingMode::TowardNegative).0
+                            != self.overflowing_mul_round(rhs, RoundingMode::TowardPositive).0 {
+                            raise_rounded(res.bits == 0);
+                        }
+                        res
+                    }
+                    _ => {
+                        raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                        if (self.bin_sign() ^ rhs.bin_sign()) == 1 { Self::MIN } else { Self::MAX }
+                    }
+                }
+            }
+
+            /// Divide, saturating at MIN/MAX.
+            /// 
+            /// Dividing by zero saturates to MAX for a positive dividend, MIN for a negative dividend, and returns zero for 0 / 0.
+            /// Raises the same flags as saturating_mul, and the divide-by-zero flag when dividing by zero
+            pub fn saturating_div(self, rhs: Self) -> Self {
+                if rhs.bits == 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    if self.bits == 0 { return self; }
+                    if self.bin_sign() == 1 { return Self::MIN; }
+                    return Self::MAX;
+                }
+                match self.overflowing_div(rhs) {
+                    (res, false) => {
+                        let num = (self.bits as $sint).unsigned_abs() as u128;
+                        let den = (rhs.bits as $sint).unsigned_abs() as u128;
+                        if recording() && !div_is_exact(num, den, F) {
+                            raise_rounded(res.bits == 0);
+                        }
+                        res
+                    }
+                    _ => {
+                        raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                        if (self.bin_sign() ^ rhs.bin_sign()) == 1 { Self::MIN } else { Self::MAX }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[macro_export]
+/// Checked, wrapping, overflowing and saturating arithmetic for unsigned types
+/// 
+/// Built on the overflowing_mul and overflowing_div of each width
+macro_rules! arith_unsigned {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Add, returning the sum wrapped to the bit width and whether it overflowed
+            pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (bits, overflow) = <$uint>::overflowing_add(self.bits, rhs.bits);
+                (Self { bits }, overflow)
+            }
+
+            /// Subtract, returning the difference wrapped to the bit width and whether it overflowed
+            pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (bits, overflow) = <$uint>::overflowing_sub(self.bits, rhs.bits);
+                (Self { bits }, overflow)
+            }
+
+            /// Add, returning None on overflow
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_add(rhs) {
+                    (res, false) => Some(res),
+                    _ => None,
+                }
+            }
+
+            /// Subtract, returning None on overflow
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_sub(rhs) {
+                    (res, false) => Some(res),
+                    _ => None,
+                }
+            }
+
+            /// Multiply, returning None on overflow
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                match self.overflowing_mul(rhs) {
+                    (res, false) => Some(res),
+                    _ => None,
+                }
+            }
+
+            /// Divide, returning None when dividing by zero or on overflow
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                if rhs.bits == 0 { return None; }
+                match self.overflowing_div(rhs) {
+                    (res, false) => Some(res),
+                    _ => None,
+                }
+            }
+
+            /// Add, wrapping around at the boundary of the type
+            pub fn wrapping_add(self, rhs: Self) -> Self {
+                self.overflowing_add(rhs).0
+            }
+
+            /// Subtract, wrapping around at the boundary of the type
+            pub fn wrapping_sub(self, rhs: Self) -> Self {
+                self.overflowing_sub(rhs).0
+            }
+
+            /// Multiply, wrapping around at the boundary of the type
+            pub fn wrapping_mul(self, rhs: Self) -> Self {
+                self.overflowing_mul(rhs).0
+            }
+
+            /// Divide, wrapping around at the boundary of the type. Panics when dividing by zero
+            pub fn wrapping_div(self, rhs: Self) -> Self {
+                self.overflowing_div(rhs).0
+            }
+
+            /// Add, saturating at MAX
+            /// 
+            /// Raises the overflow and inexact flags when saturating
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                self.checked_add(rhs).unwrap_or_else(|| {
+                    raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                    Self::MAX
+                })
+            }
+
+            /// Subtract, saturating at MIN
+            /// 
+            /// Raises the overflow and inexact flags when saturating
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                self.checked_sub(rhs).unwrap_or_else(|| {
+                    raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                    Self::MIN
+                })
+            }
+
+            /// Multiply, saturating at MAX
+            /// 
+            /// Raises the overflow and inexact flags when saturating, the inexact flag when rounding,
+            /// and the underflow flag when a nonzero product rounds to zero
+            pub fn saturating_mul(self, rhs: Self) -> Self {
+                match self.overflowing_mul(rhs) {
+                    (res, false) => {
+                        // The product is exact when rounding down and up agree
+                        if recording() && self.overflowing_mul_round(rhs, RoundingMode::TowardZero).0
+                            != self.overflowing_mul_round(rhs, RoundingMode::TowardPositive).0 {
+                            raise_rounded(res.bits == 0);
+                        }
+                        res
+                    }
+                    _ => {
+                        raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                        Self::MAX
+                    }
+                }
+            }
+
+            /// Divide, saturating at MAX.
+            /// 
+            /// Dividing by zero saturates to MAX, and returns zero for 0 / 0.
+            /// Raises the same flags as saturating_mul, and the divide-by-zero flag when dividing by zero
+            pub fn saturating_div(self, rhs: Self) -> Self {
+                if rhs.bits == 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    if self.bits == 0 { return self; }
+                    return Self::MAX;
+                }
+                match self.overflowing_div(rhs) {
+                    (res, false) => {
+                        if recording() && !div_is_exact(self.bits as u128, rhs.bits as u128, F) {
+                            raise_rounded(res.bits == 0);
+                        }
+                        res
+                    }
+                    _ => {
+                        raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                        Self::MAX
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[macro_export]
+/// Multiplication for signed types
+macro_rules! mul_same_signed {
+    ($target:ident, $uint:ty, $sint:ty, $duint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Multiply into the double-width storage with `T` fraction bits.
+            /// 
+            /// The exact product has `2F` fraction bits, so it is kept without rounding when `T >= 2F`
+            /// and the double-width range holds it, otherwise it rounds to nearest (ties to even) and saturates
+            pub fn widening_mul<const T: u32>(self, rhs: Self) -> $target<$duint, T> {
+                let product = (self.bits as $sint as i128) * (rhs.bits as $sint as i128);
+                $target::<$duint, T>::from_parts(product < 0, product.unsigned_abs(), 2 * F, RoundingMode::NearestEven)
+            }
+
+            /// Multiply, returning the product wrapped to the bit width and whether it overflowed
+            /// 
+            /// The exact product is rounded to nearest (ties to even)
+            pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                self.overflowing_mul_round(rhs, RoundingMode::NearestEven)
+            }
+
+            /// Multiply, rounding the exact product with `mode` and saturating at MIN/MAX
+            pub fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self {
+                match self.overflowing_mul_round(rhs, mode) {
+                    (res, false) => {
+                        if recording() && self.overflowing_mul_round(rhs, RoundingMode::TowardNegative).0
+                            != self.overflowing_mul_round(rhs, RoundingMode::TowardPositive).0 {
+                            raise_rounded(res.bits == 0);
+                        }
+                        res
+                    }
+                    _ => Self::saturated((self.bin_sign() ^ rhs.bin_sign()) == 1),
+                }
+            }
+
+            /// Multiply, rounding the exact product with `mode`, returning the product wrapped to the bit width and whether it overflowed
+            pub(crate) fn overflowing_mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool) {
+                let negative = (self.bin_sign() ^ rhs.bin_sign()) == 1;
+                let lhs_mag = (self.bits as $sint).unsigned_abs() as $duint;
+                let rhs_mag = (rhs.bits as $sint).unsigned_abs() as $duint;
+                // Both magnitudes are at most half the double width, so the product cannot overflow
+                let full = lhs_mag * rhs_mag;
+                let mag = round_shr(full as u128, F, negative, mode) as $duint;
+                if negative {
+                    (Self { bits: (mag as $uint).wrapping_neg() }, mag > Self::MINB as $duint)
+                } else {
+                    (Self { bits: mag as $uint }, mag > Self::MAXB as $duint)
+                }
+            }
+        }
+
+        impl<const F: u32> Mul for $target<$uint, F> {
+            type Output = Self;
+            /// Multiply two values, rounding to nearest (ties to even) and saturating at MIN/MAX
+            fn mul(self, rhs: Self) -> Self {
+                self.saturating_mul(rhs)
+            }
+        }
+
+        impl<const F: u32> MulAssign for $target<$uint, F> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs
+            }
+        }
+    }
+}
+
+#[macro_export]
+/// Multiplication for signed 128-bit types
+macro_rules! mul_same_signed_128 {
+    ($target:ident, $uint:ident, $dsint:ident, $duint:ident) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Multiply into an exact i256 with `2F` fraction bits
+            pub fn widening_mul(self, rhs: Self) -> $dsint {
+                <$dsint>::from_mul(self.bits as i128, rhs.bits as i128)
+            }
+
+            /// Multiply, returning the product wrapped to the bit width and whether it overflowed
+            /// 
+            /// The exact product is rounded to nearest (ties to even)
+            pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                self.overflowing_mul_round(rhs, RoundingMode::NearestEven)
+            }
+
+            /// Multiply, rounding the exact product with `mode` and saturating at MIN/MAX
+            pub fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self {
+                match self.overflowing_mul_round(rhs, mode) {
+                    (res, false) => {
+                        if recording() && self.overflowing_mul_round(rhs, RoundingMode::TowardNegative).0
+                            != self.overflowing_mul_round(rhs, RoundingMode::TowardPositive).0 {
+                            raise_rounded(res.bits == 0);
+                        }
+                        res
+                    }
+                    _ => Self::saturated((self.bin_sign() ^ rhs.bin_sign()) == 1),
+                }
+            }
+
+            /// Multiply, rounding the exact product with `mode`, returning the product wrapped to the bit width and whether it overflowed
+            pub(crate) fn overflowing_mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool) {
+                let product = <$dsint>::from_mul(self.bits as i128, rhs.bits as i128);
+                let full = product.unsigned_abs();
+                let mut mag = full >> F as u128;
+                if F > 0 {
+                    let rem = full & ((<$duint>::from(1) << F as u128) - 1);
+                    let half = rem.cmp(&(<$duint>::from(1) << (F - 1) as u128));
+                    let up = mode.round_up(product.is_negative(), mag.bits[1] & 1 == 1, half, rem != <$duint>::MIN);
+                    mag += up as u128;
+                }
+                if product.is_negative() {
+                    (Self { bits: $uint::from(mag).wrapping_neg() }, mag > Self::MINB)
+                } else {
+                    (Self { bits: $uint::from(mag) }, mag > Self::MAXB)
+                }
+            }
+        }
+
+        impl<const F: u32> Mul for $target<$uint, F> {
+            type Output = Self;
+            /// Multiply two values, rounding to nearest (ties to even) and saturating at MIN/MAX
+            fn mul(self, rhs: Self) -> Self {
+                self.saturating_mul(rhs)
+            }
+        }
+
+        impl<const F: u32> MulAssign for $target<$uint, F> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs
+            }
+        }
+    }
+}
+
+#[macro_export]
+/// Multiplication for unsigned types
+macro_rules! mul_same_unsigned {
+    ($target:ident, $uint:ty, $duint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Multiply into the double-width storage with `T` fraction bits.
+            /// 
+            /// The exact product has `2F` fraction bits, so it is kept without rounding when `T >= 2F`
+            /// and the double-width range holds it, otherwise it rounds to nearest (ties to even) and saturates
+            pub fn widening_mul<const T: u32>(self, rhs: Self) -> $target<$duint, T> {
+                let product = (self.bits as u128) * (rhs.bits as u128);
+                $target::<$duint, T>::from_parts(false, product, 2 * F, RoundingMode::NearestEven)
+            }
+
+            /// Multiply, returning the product wrapped to the bit width and whether it overflowed
+            /// 
+            /// The exact product is rounded to nearest (ties to even)
+            pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                self.overflowing_mul_round(rhs, RoundingMode::NearestEven)
+            }
+
+            /// Multiply, rounding the exact product with `mode` and saturating at MAX
+            pub fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self {
+                match self.overflowing_mul_round(rhs, mode) {
+                    (res, false) => {
+                        if recording() && self.overflowing_mul_round(rhs, RoundingMode::TowardNegative).0
+                            != self.overflowing_mul_round(rhs, RoundingMode::TowardPositive).0 {
+                            raise_rounded(res.bits == 0);
+                        }
+                        res
+                    }
+                    _ => Self::saturated(false),
+                }
+            }
+
+            /// Multiply, rounding the exact product with `mode`, returning the product wrapped to the bit width and whether it overflowed
+            pub(crate) fn overflowing_mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool) {
+                let full = (self.bits as $duint) * (rhs.bits as $duint);
+                let bits = round_shr(full as u128, F, false, mode) as $duint;
+                (Self { bits: bits as $uint }, bits > <$uint>::MAX as $duint)
+            }
+        }
+
+        impl<const F: u32> Mul for $target<$uint, F> {
+            type Output = Self;
+            /// Multiply two values, rounding to nearest (ties to even) and saturating at MAX
+            fn mul(self, rhs: Self) -> Self {
+                self.saturating_mul(rhs)
+            }
+        }
+
+        impl<const F: u32> MulAssign for $target<$uint, F> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs
+            }
+        }
+    }
+}
+
+#[macro_export]
+/// Multiplication for unsigned 128-bit types
+macro_rules! mul_same_unsigned_128 {
+    ($target:ident, $uint:ident, $duint:ident) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Multiply into an exact u256 with `2F` fraction bits
+            pub fn widening_mul(self, rhs: Self) -> $duint {
+                <$duint>::from_mul(self.bits, rhs.bits)
+            }
+
+            /// Multiply, returning the product wrapped to the bit width and whether it overflowed
+            /// 
+            /// The exact product is rounded to nearest (ties to even)
+            pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                self.overflowing_mul_round(rhs, RoundingMode::NearestEven)
+            }
+
+            /// Multiply, rounding the exact product with `mode` and saturating at MAX
+            pub fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self {
+                match self.overflowing_mul_round(rhs, mode) {
+                    (res, false) => {
+                        if recording() && self.overflowing_mul_round(rhs, RoundingMode::TowardNegative).0
+                            != self.overflowing_mul_round(rhs, RoundingMode::TowardPositive).0 {
+                            raise_rounded(res.bits == 0);
+                        }
+                        res
+                    }
+                    _ => Self::saturated(false),
+                }
+            }
+
+            /// Multiply, rounding the exact product with `mode`, returning the product wrapped to the bit width and whether it overflowed
+            pub(crate) fn overflowing_mul_round(self, rhs: Self, mode: RoundingMode) -> (Self, bool) {
+                let full = <$duint>::from_mul(self.bits, rhs.bits);
+                let mut bits = full >> F as u128;
+                if F > 0 {
+                    let rem = full & ((<$duint>::from(1) << F as u128) - 1);
+                    let half = rem.cmp(&(<$duint>::from(1) << (F - 1) as u128));
+                    let up = mode.round_up(false, bits.bits[1] & 1 == 1, half, rem != <$duint>::MIN);
+                    bits += up as u128;
+                }
+                (Self { bits: $uint::from(bits) }, bits > $uint::MAX)
+            }
+        }
+
+        impl<const F: u32> Mul for $target<$uint, F> {
+            type Output = Self;
+            /// Multiply two values, rounding to nearest (ties to even) and saturating at MAX
+            fn mul(self, rhs: Self) -> Self {
+                self.saturating_mul(rhs)
+            }
+        }
+
+        impl<const F: u32> MulAssign for $target<$uint, F> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs
+            }
+        }
+    }
+}
+
+#[macro_export]
+/// Division for signed types
+macro_rules! div_same_signed {
+    ($target:ident, $uint:ty, $sint:ty, $duint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Divide, returning the quotient wrapped to the bit width and whether it overflowed
+            /// 
+            /// The quotient is rounded to the nearest value (ties to even). Panics when dividing by zero
+            pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                assert!(rhs.bits != 0, "attempt to divide by zero");
+                let negative = (self.bin_sign() ^ rhs.bin_sign()) == 1;
+                // Widen the dividend so the quotient keeps all of its fraction bits
+                let num = ((self.bits as $sint).unsigned_abs() as $duint) << Self::FRC;
+                let den = (rhs.bits as $sint).unsigned_abs() as $duint;
+                let mut quo = num / den;
+                let rem = num % den;
+                if ((rem << 1) > den) | (((rem << 1) == den) & (quo & 1 == 1)) {
+                    quo += 1;
+                }
+                if negative {
+                    (Self { bits: (quo as $uint).wrapping_neg() }, quo > Self::MINB as $duint)
+                } else {
+                    (Self { bits: quo as $uint }, quo > Self::MAXB as $duint)
+                }
+            }
+        }
+
+        impl<const F: u32> Div for $target<$uint, F> {
+            type Output = Self;
+            /// Divide two certums, rounding the quotient to the nearest value (ties to even).
+            /// 
+            /// Quotients out of range saturate to MIN/MAX. 
+            /// Dividing by zero saturates to MAX for a positive dividend, MIN for a negative dividend, and returns zero for 0 / 0
+            fn div(self, rhs: Self) -> Self {
+                self.saturating_div(rhs)
+            }
+        }
+
+        impl<const F: u32> DivAssign for $target<$uint, F> {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs
+            }
+        }
+    }
+}
+
+#[macro_export]
+/// Division for signed 128-bit types
+macro_rules! div_same_signed_128 {
+    ($target:ident, $uint:ident, $sint:ident, $duint:ident) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Divide, returning the quotient wrapped to the bit width and whether it overflowed
+            /// 
+            /// The quotient is rounded to the nearest value (ties to even). Panics when dividing by zero
+            pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                assert!(rhs.bits != 0, "attempt to divide by zero");
+                let negative = (self.bin_sign() ^ rhs.bin_sign()) == 1;
+                // Widen the dividend into a quire so the quotient keeps all of its fraction bits
+                let num = <$duint>::from((self.bits as $sint).unsigned_abs()) << Self::FRC;
+                let den = <$duint>::from((rhs.bits as $sint).unsigned_abs());
+                let (mut quo, rem) = num.div_rem(den);
+                if ((rem << 1) > den) | (((rem << 1) == den) & (quo.bits[1] & 1 == 1)) {
+                    quo += 1;
+                }
+                if negative {
+                    (Self { bits: $uint::from(quo).wrapping_neg() }, quo > Self::MINB)
+                } else {
+                    (Self { bits: $uint::from(quo) }, quo > Self::MAXB)
+                }
+            }
+        }
+
+        impl<const F: u32> Div for $target<$uint, F> {
+            type Output = Self;
+            /// Divide two certums, rounding the quotient to the nearest value (ties to even).
+            /// 
+            /// Quotients out of range saturate to MIN/MAX. 
+            /// Dividing by zero saturates to MAX for a positive dividend, MIN for a negative dividend, and returns zero for 0 / 0
+            fn div(self, rhs: Self) -> Self {
+                self.saturating_div(rhs)
+            }
+        }
+
+        impl<const F: u32> DivAssign for $target<$uint, F> {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs
+            }
+        }
+    }
+}
+
+#[macro_export]
+/// Division for unsigned types
+macro_rules! div_same_unsigned {
+    ($target:ident, $uint:ty, $duint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Divide, returning the quotient wrapped to the bit width and whether it overflowed
+            /// 
+            /// The quotient is rounded to the nearest value (ties to even). Panics when dividing by zero
+            pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                assert!(rhs.bits != 0, "attempt to divide by zero");
+                // Widen the dividend so the quotient keeps all of its fraction bits
+                let num = (self.bits as $duint) << Self::FRC;
+                let den = rhs.bits as $duint;
+                let mut quo = num / den;
+                let rem = num % den;
+                if ((rem << 1) > den) | (((rem << 1) == den) & (quo & 1 == 1)) {
+                    quo += 1;
+                }
+                (Self { bits: quo as $uint }, quo > <$uint>::MAX as $duint)
+            }
+        }
+
+        impl<const F: u32> Div for $target<$uint, F> {
+            type Output = Self;
+            /// Divide two certums, rounding the quotient to the nearest value (ties to even).
+            /// 
+            /// Quotients out of range saturate to MAX. 
+            /// Dividing by zero saturates to MAX, and returns zero for 0 / 0
+            fn div(self, rhs: Self) -> Self {
+                self.saturating_div(rhs)
+            }
+        }
+
+        impl<const F: u32> DivAssign for $target<$uint, F> {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs
+            }
+        }
+    }
+}
+
+#[macro_export]
+/// Division for unsigned 128-bit types
+macro_rules! div_same_unsigned_128 {
+    ($target:ident, $uint:ident, $duint:ident) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Divide, returning the quotient wrapped to the bit width and whether it overflowed
+            /// 
+            /// The quotient is rounded to the nearest value (ties to even). Panics when dividing by zero
+            pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                assert!(rhs.bits != 0, "attempt to divide by zero");
+                // Widen the dividend into a quire so the quotient keeps all of its fraction bits
+                let num = <$duint>::from(self.bits) << Self::FRC;
+                let den = <$duint>::from(rhs.bits);
+                let (mut quo, rem) = num.div_rem(den);
+                if ((rem << 1) > den) | (((rem << 1) == den) & (quo.bits[1] & 1 == 1)) {
+                    quo += 1;
+                }
+                (Self { bits: $uint::from(quo) }, quo > $uint::MAX)
+            }
+        }
+
+        impl<const F: u32> Div for $target<$uint, F> {
+            type Output = Self;
+            /// Divide two certums, rounding the quotient to the nearest value (ties to even).
+            /// 
+            /// Quotients out of range saturate to MAX. 
+            /// Dividing by zero saturates to MAX, and returns zero for 0 / 0
+            fn div(self, rhs: Self) -> Self {
+                self.saturating_div(rhs)
+            }
+        }
+
+        impl<const F: u32> DivAssign for $target<$uint, F> {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs
+            }
+        }
+    }
+}
+
+#[macro_export]
+/// Exact scaling by plain integers and integer conversion for signed types
+macro_rules! int_ops_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Convert from a whole number, saturating values out of range
+            pub fn from_int(int: i128) -> Self {
+                Self::from_parts(int < 0, int.unsigned_abs(), 0, RoundingMode::NearestEven)
+            }
+
+            /// Convert to a whole number, rounding the fraction with `mode`.
+            /// 
+            /// TowardNegative is floor, TowardZero truncates and NearestEven or NearestAway round
+            pub fn to_int(self, mode: RoundingMode) -> i128 {
+                let (neg, mag) = self.to_parts();
+                let int = round_shr(mag, F, neg, mode) as i128;
+                if neg { int.wrapping_neg() } else { int }
+            }
+        }
+
+        impl<const F: u32> Mul<i32> for $target<$uint, F> {
+            type Output = Self;
+            /// Multiply by a whole number exactly, saturating at MIN/MAX
+            fn mul(self, rhs: i32) -> Self {
+                let (neg, mag) = self.to_parts();
+                let mag = mag.checked_mul(rhs.unsigned_abs() as u128).unwrap_or_else(|| {
+                    raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                    u128::MAX
+                });
+                Self::from_parts(neg ^ (rhs < 0), mag, F, RoundingMode::NearestEven)
+            }
+        }
+
+        impl<const F: u32> Div<i32> for $target<$uint, F> {
+            type Output = Self;
+            /// Divide by a whole number, rounding to the nearest value (ties to even).
+            /// 
+            /// Dividing by zero saturates to MAX for a positive dividend, MIN for a negative dividend, and returns zero for 0 / 0
+            fn div(self, rhs: i32) -> Self {
+                let (neg, mag) = self.to_parts();
+                if rhs == 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    return if mag == 0 { self } else if neg { Self::MIN } else { Self::MAX };
+                }
+                let neg = neg ^ (rhs < 0);
+                let den = rhs.unsigned_abs() as u128;
+                let (quo, rem) = (mag / den, mag % den);
+                let up = RoundingMode::NearestEven.round_up(neg, quo & 1 == 1, (rem * 2).cmp(&den), rem != 0);
+                let res = Self::from_parts(neg, quo + up as u128, F, RoundingMode::NearestEven);
+                if rem != 0 { raise_rounded(res.bits == 0); }
+                res
+            }
+        }
+
+        impl<const F: u32> Shl<u32> for $target<$uint, F> {
+            type Output = Self;
+            /// Multiply by 2 ^ rhs exactly, saturating at MIN/MAX
+            fn shl(self, rhs: u32) -> Self {
+                let (neg, mag) = self.to_parts();
+                if mag != 0 && mag.leading_zeros() < rhs { return Self::saturated(neg); }
+                Self::from_parts(neg, mag << rhs.min(127), F, RoundingMode::NearestEven)
+            }
+        }
+
+        impl<const F: u32> Shr<u32> for $target<$uint, F> {
+            type Output = Self;
+            /// Divide by 2 ^ rhs, rounding to the nearest value (ties to even)
+            fn shr(self, rhs: u32) -> Self {
+                let (neg, mag) = self.to_parts();
+                Self::from_parts(neg, mag, F.saturating_add(rhs), RoundingMode::NearestEven)
+            }
+        }
+
+        impl<const F: u32> MulAssign<i32> for $target<$uint, F> {
+            fn mul_assign(&mut self, rhs: i32) {
+                *self = *self * rhs
+            }
+        }
+
+        impl<const F: u32> DivAssign<i32> for $target<$uint, F> {
+            fn div_assign(&mut self, rhs: i32) {
+                *self = *self / rhs
+            }
+        }
+
+        impl<const F: u32> ShlAssign<u32> for $target<$uint, F> {
+            fn shl_assign(&mut self, rhs: u32) {
+                *self = *self << rhs
+            }
+        }
+
+        impl<const F: u32> ShrAssign<u32> for $target<$uint, F> {
+            fn shr_assign(&mut self, rhs: u32) {
+                *self = *self >> rhs
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Exact scaling by plain integers and integer conversion for unsigned types
+macro_rules! int_ops_unsigned {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Convert from a whole number, saturating values out of range
+            pub fn from_int(int: u128) -> Self {
+                Self::from_parts(false, int, 0, RoundingMode::NearestEven)
+            }
+
+            /// Convert to a whole number, rounding the fraction with `mode`.
+            /// 
+            /// TowardNegative and TowardZero are floor, NearestEven or NearestAway round
+            pub fn to_int(self, mode: RoundingMode) -> u128 {
+                round_shr(self.bits as u128, F, false, mode)
+            }
+        }
+
+        impl<const F: u32> Mul<u32> for $target<$uint, F> {
+            type Output = Self;
+            /// Multiply by a whole number exactly, saturating at MAX
+            fn mul(self, rhs: u32) -> Self {
+                let mag = (self.bits as u128).checked_mul(rhs as u128).unwrap_or_else(|| {
+                    raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                    u128::MAX
+                });
+                Self::from_parts(false, mag, F, RoundingMode::NearestEven)
+            }
+        }
+
+        impl<const F: u32> Div<u32> for $target<$uint, F> {
+            type Output = Self;
+            /// Divide by a whole number, rounding to the nearest value (ties to even).
+            /// 
+            /// Dividing by zero saturates to MAX, and returns zero for 0 / 0
+            fn div(self, rhs: u32) -> Self {
+                if rhs == 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    return if self.bits == 0 { self } else { Self::MAX };
+                }
+                let (num, den) = (self.bits as u128, rhs as u128);
+                let (quo, rem) = (num / den, num % den);
+                let up = RoundingMode::NearestEven.round_up(false, quo & 1 == 1, (rem * 2).cmp(&den), rem != 0);
+                let res = Self::from_parts(false, quo + up as u128, F, RoundingMode::NearestEven);
+                if rem != 0 { raise_rounded(res.bits == 0); }
+                res
+            }
+        }
+
+        impl<const F: u32> Shl<u32> for $target<$uint, F> {
+            type Output = Self;
+            /// Multiply by 2 ^ rhs exactly, saturating at MAX
+            fn shl(self, rhs: u32) -> Self {
+                let mag = self.bits as u128;
+                if mag != 0 && mag.leading_zeros() < rhs { return Self::saturated(false); }
+                Self::from_parts(false, mag << rhs.min(127), F, RoundingMode::NearestEven)
+            }
+        }
+
+        impl<const F: u32> Shr<u32> for $target<$uint, F> {
+            type Output = Self;
+            /// Divide by 2 ^ rhs, rounding to the nearest value (ties to even)
+            fn shr(self, rhs: u32) -> Self {
+                Self::from_parts(false, self.bits as u128, F.saturating_add(rhs), RoundingMode::NearestEven)
+            }
+        }
+
+        impl<const F: u32> MulAssign<u32> for $target<$uint, F> {
+            fn mul_assign(&mut self, rhs: u32) {
+                *self = *self * rhs
+            }
+        }
+
+        impl<const F: u32> DivAssign<u32> for $target<$uint, F> {
+            fn div_assign(&mut self, rhs: u32) {
+                *self = *self / rhs
+            }
+        }
+
+        impl<const F: u32> ShlAssign<u32> for $target<$uint, F> {
+            fn shl_assign(&mut self, rhs: u32) {
+                *self = *self << rhs
+            }
+        }
+
+        impl<const F: u32> ShrAssign<u32> for $target<$uint, F> {
+            fn shr_assign(&mut self, rhs: u32) {
+                *self = *self >> rhs
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Correctly rounded roots for signed types, using integer arithmetic only
+macro_rules! roots_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Square root, correctly rounded to the nearest value and saturating at MAX.
+            /// 
+            /// Panics when the value is negative
+            pub fn sqrt(self) -> Self {
+                self.checked_sqrt().expect("argument of square root cannot be negative")
+            }
+
+            /// Square root, correctly rounded to the nearest value and saturating at MAX.
+            /// 
+            /// Returns None when the value is negative
+            pub fn checked_sqrt(self) -> Option<Self> {
+                let (neg, mag) = self.to_parts();
+                if neg { return None; }
+                let root = sqrt_bits(mag, F);
+                Some(if root > Self::MAXB as u128 { Self::saturated(false) } else { Self { bits: u128::from(root) as $uint } })
+            }
+
+            /// Reciprocal square root, correctly rounded to the nearest value and saturating at MAX.
+            /// 
+            /// The reciprocal square root of zero is MAX. Panics when the value is negative
+            pub fn rsqrt(self) -> Self {
+                self.checked_rsqrt().expect("argument of reciprocal square root cannot be negative")
+            }
+
+            /// Reciprocal square root, correctly rounded to the nearest value and saturating at MAX.
+            /// 
+            /// The reciprocal square root of zero is MAX. Returns None when the value is negative
+            pub fn checked_rsqrt(self) -> Option<Self> {
+                let (neg, mag) = self.to_parts();
+                if neg { return None; }
+                if mag == 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    return Some(Self::MAX);
+                }
+                Some(match rsqrt_bits(mag, F) {
+                    Some(root) if root <= Self::MAXB as u128 => Self { bits: root as $uint },
+                    _ => Self::saturated(false),
+                })
+            }
+
+            /// Cube root, correctly rounded to the nearest value and saturating at MIN/MAX
+            pub fn cbrt(self) -> Self {
+                let (neg, mag) = self.to_parts();
+                let root = cbrt_bits(mag, F);
+                let max = if neg { Self::MINB as u128 } else { Self::MAXB as u128 };
+                if root > max { return Self::saturated(neg); }
+                let bits = u128::from(root) as $uint;
+                Self { bits: if neg { bits.wrapping_neg() } else { bits } }
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Correctly rounded roots for unsigned types, using integer arithmetic only
+macro_rules! roots_unsigned {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Square root, correctly rounded to the nearest value and saturating at MAX
+            pub fn sqrt(self) -> Self {
+                let root = sqrt_bits(self.bits as u128, F);
+                if root > <$uint>::MAX as u128 { Self::saturated(false) } else { Self { bits: u128::from(root) as $uint } }
+            }
+
+            /// Reciprocal square root, correctly rounded to the nearest value and saturating at MAX.
+            /// 
+            /// The reciprocal square root of zero is MAX
+            pub fn rsqrt(self) -> Self {
+                if self.bits == 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    return Self::MAX;
+                }
+                match rsqrt_bits(self.bits as u128, F) {
+                    Some(root) if root <= <$uint>::MAX as u128 => Self { bits: root as $uint },
+                    _ => Self::saturated(false),
+                }
+            }
+
+            /// Cube root, correctly rounded to the nearest value and saturating at MAX
+            pub fn cbrt(self) -> Self {
+                let root = cbrt_bits(self.bits as u128, F);
+                if root > <$uint>::MAX as u128 { Self::saturated(false) } else { Self { bits: u128::from(root) as $uint } }
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Trigonometric functions for signed types, using integer arithmetic only
+/// 
+/// Every function evaluates at 192 fraction bits before rounding once to the nearest value,
+/// so results are bit-identical on every platform and within 1 ULP, correctly rounded unless the exact result
+/// lies within 2^-180 of a tie. tan is only that accurate more than 2^-60 away from its poles
+macro_rules! trig_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Sine, in radians
+            pub fn sin(self) -> Self {
+                self.sin_cos().0
+            }
+
+            /// Cosine, in radians
+            pub fn cos(self) -> Self {
+                self.sin_cos().1
+            }
+
+            /// Sine and cosine, in radians, from a single argument reduction
+            pub fn sin_cos(self) -> (Self, Self) {
+                let (neg, mag) = self.to_parts();
+                let ((sneg, sin), (cneg, cos)) = trig::sin_cos(neg, mag, F);
+                (Self::from_wide(sneg, sin, 0), Self::from_wide(cneg, cos, 0))
+            }
+
+            /// Tangent, in radians, saturating at MIN/MAX near the poles
+            pub fn tan(self) -> Self {
+                let (neg, mag) = self.to_parts();
+                let ((sneg, sin), (cneg, cos)) = trig::sin_cos(neg, mag, F);
+                if cos == u256::MIN { return Self::saturated(sneg); }
+                Self::from_wide(sneg ^ cneg, wide::div(sin, cos), 0)
+            }
+
+            /// Arcsine in radians, in [-π / 2, π / 2].
+            /// 
+            /// Panics when the value is outside [-1, 1]
+            pub fn asin(self) -> Self {
+                self.checked_asin().expect("argument of arcsine must be within [-1, 1]")
+            }
+
+            /// Arcsine in radians, in [-π / 2, π / 2].
+            /// 
+            /// Returns None when the value is outside [-1, 1]
+            pub fn checked_asin(self) -> Option<Self> {
+                let (neg, mag) = self.to_parts();
+                let (val, comp) = trig::unit_complement(mag, F)?;
+                let (_, angle) = trig::atan2(false, val, false, comp);
+                Some(Self::from_wide(neg, angle, 0))
+            }
+
+            /// Arccosine in radians, in [0, π].
+            /// 
+            /// Panics when the value is outside [-1, 1]
+            pub fn acos(self) -> Self {
+                self.checked_acos().expect("argument of arccosine must be within [-1, 1]")
+            }
+
+            /// Arccosine in radians, in [0, π].
+            /// 
+            /// Returns None when the value is outside [-1, 1]
+            pub fn checked_acos(self) -> Option<Self> {
+                let (neg, mag) = self.to_parts();
+                let (val, comp) = trig::unit_complement(mag, F)?;
+                let (_, angle) = trig::atan2(false, comp, neg, val);
+                Some(Self::from_wide(false, angle, 0))
+            }
+
+            /// Arctangent in radians, in [-π / 2, π / 2]
+            pub fn atan(self) -> Self {
+                let (neg, mag) = self.to_parts();
+                Self::from_wide(neg, trig::atan(mag, F), 0)
+            }
+
+            /// Four quadrant arctangent of `self` (y) and `x` in radians, in [-π, π]
+            /// 
+            /// The angle of the origin is zero, and points on the negative x axis give π
+            pub fn atan2(self, x: Self) -> Self {
+                let (yneg, ymag) = self.to_parts();
+                let (xneg, xmag) = x.to_parts();
+                let (neg, angle) = trig::atan2(yneg, u256::from(ymag), xneg, u256::from(xmag));
+                Self::from_wide(neg, angle, 0)
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Exponentials, logarithms and powers for signed types, using integer arithmetic only
+/// 
+/// Every function evaluates at 192 fraction bits before rounding once to the nearest value, so results are
+/// bit-identical on every platform. With n-bit storage, exp, exp2, ln, log2, log10 and powi are within 1 ULP,
+/// and correctly rounded unless the exact result lies within 2^(n - 186) ULP of a tie (2^-58 ULP for 128-bit storage).
+/// powf is as accurate for 8 to 64-bit storage, while for 128-bit storage its error grows with the exponent
+/// to at most 32 ULP for layouts with around 60 fraction bits
+macro_rules! exp_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// e^self, saturating at MAX
+            pub fn exp(self) -> Self {
+                let (neg, mag) = self.to_parts();
+                if !wide::fits(mag, F) { return Self::exp_limit(neg); }
+                let (mant, pow) = exp::exp(neg, wide::from_bits(mag, F));
+                Self::from_wide(false, mant, pow)
+            }
+
+            /// e^self, or None when it exceeds MAX
+            pub fn checked_exp(self) -> Option<Self> {
+                let (neg, mag) = self.to_parts();
+                if !wide::fits(mag, F) { return if neg { Some(Self { bits: 0 }) } else { None }; }
+                let (mant, pow) = exp::exp(neg, wide::from_bits(mag, F));
+                Self::checked_from_wide(false, mant, pow)
+            }
+
+            /// 2^self, saturating at MAX
+            pub fn exp2(self) -> Self {
+                let (neg, mag) = self.to_parts();
+                if !wide::fits(mag, F) { return Self::exp_limit(neg); }
+                let (mant, pow) = exp::exp2(neg, wide::from_bits(mag, F));
+                Self::from_wide(false, mant, pow)
+            }
+
+            /// 2^self, or None when it exceeds MAX
+            pub fn checked_exp2(self) -> Option<Self> {
+                let (neg, mag) = self.to_parts();
+                if !wide::fits(mag, F) { return if neg { Some(Self { bits: 0 }) } else { None }; }
+                let (mant, pow) = exp::exp2(neg, wide::from_bits(mag, F));
+                Self::checked_from_wide(false, mant, pow)
+            }
+
+            /// The exponential of an argument of 2^64 or more in magnitude, MAX when positive and zero when negative
+            fn exp_limit(neg: bool) -> Self {
+                if !neg { return Self::saturated(false); }
+                raise_rounded(true);
+                Self { bits: 0 }
+            }
+
+            /// Logarithm with `log`, saturating at MIN/MAX. The logarithm of zero is MIN
+            fn log_with(self, log: fn(u128, u32) -> (bool, u256)) -> Self {
+                let (neg, mag) = self.to_parts();
+                assert!(!neg, "argument of logarithm cannot be negative");
+                if mag == 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    return Self::MIN;
+                }
+                let (neg, val) = log(mag, F);
+                Self::from_wide(neg, val, 0)
+            }
+
+            /// Logarithm with `log`, or None when the value is not positive or the result is out of range
+            fn checked_log_with(self, log: fn(u128, u32) -> (bool, u256)) -> Option<Self> {
+                let (neg, mag) = self.to_parts();
+                if neg || mag == 0 { return None; }
+                let (neg, val) = log(mag, F);
+                Self::checked_from_wide(neg, val, 0)
+            }
+
+            /// Natural logarithm, saturating at MIN/MAX. The logarithm of zero is MIN.
+            /// 
+            /// Panics when the value is negative
+            pub fn ln(self) -> Self {
+                self.log_with(exp::ln)
+            }
+
+            /// Natural logarithm, or None when the value is not positive or the result is out of range
+            pub fn checked_ln(self) -> Option<Self> {
+                self.checked_log_with(exp::ln)
+            }
+
+            /// Base 2 logarithm, saturating at MIN/MAX. The logarithm of zero is MIN.
+            /// 
+            /// Panics when the value is negative
+            pub fn log2(self) -> Self {
+                self.log_with(exp::log2)
+            }
+
+            /// Base 2 logarithm, or None when the value is not positive or the result is out of range
+            pub fn checked_log2(self) -> Option<Self> {
+                self.checked_log_with(exp::log2)
+            }
+
+            /// Base 10 logarithm, saturating at MIN/MAX. The logarithm of zero is MIN.
+            /// 
+            /// Panics when the value is negative
+            pub fn log10(self) -> Self {
+                self.log_with(exp::log10)
+            }
+
+            /// Base 10 logarithm, or None when the value is not positive or the result is out of range
+            pub fn checked_log10(self) -> Option<Self> {
+                self.checked_log_with(exp::log10)
+            }
+
+            /// Raise to an integer power, saturating at MIN/MAX.
+            /// 
+            /// Zero to the power of zero is one, and zero to a negative power is MAX
+            pub fn powi(self, n: i32) -> Self {
+                let (neg, mag) = self.to_parts();
+                if mag == 0 && n < 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    return Self::MAX;
+                }
+                self.checked_powi(n).unwrap_or_else(|| Self::saturated(neg && n % 2 != 0))
+            }
+
+            /// Raise to an integer power, or None when the result is out of range.
+            /// 
+            /// Zero to the power of zero is one, and zero to a negative power is None
+            pub fn checked_powi(self, n: i32) -> Option<Self> {
+                let (neg, mag) = self.to_parts();
+                if mag == 0 {
+                    return if n > 0 { Some(self) } else if n == 0 { Self::checked_from_wide(false, wide::ONE, 0) } else { None };
+                }
+                let (mant, pow) = exp::powi(mag, F, n);
+                Self::checked_from_wide(neg && n % 2 != 0, mant, pow)
+            }
+
+            /// Raise to the power of `y`, saturating at MIN/MAX.
+            /// 
+            /// Zero to the power of zero is one, and zero to a negative power is MAX.
+            /// Panics when the value is negative and `y` is not an integer
+            pub fn powf(self, y: Self) -> Self {
+                let (neg, mag) = self.to_parts();
+                let (yneg, ymag) = y.to_parts();
+                if mag == 0 {
+                    return self.checked_powf(y).unwrap_or_else(|| {
+                        raise(StatusFlags::DIVIDE_BY_ZERO);
+                        Self::MAX
+                    });
+                }
+                let odd = neg && Self::odd_integer(ymag).expect("negative base requires an integer exponent");
+                let (mant, pow) = exp::powf(mag, yneg, ymag, F);
+                Self::from_wide(odd, mant, pow)
+            }
+
+            /// Raise to the power of `y`, or None when the result is out of range.
+            /// 
+            /// Zero to the power of zero is one, and zero to a negative power is None.
+            /// Also returns None when the value is negative and `y` is not an integer
+            pub fn checked_powf(self, y: Self) -> Option<Self> {
+                let (neg, mag) = self.to_parts();
+                let (yneg, ymag) = y.to_parts();
+                if mag == 0 {
+                    return if ymag == 0 { Self::checked_from_wide(false, wide::ONE, 0) } else if yneg { None } else { Some(self) };
+                }
+                let odd = neg && Self::odd_integer(ymag)?;
+                let (mant, pow) = exp::powf(mag, yneg, ymag, F);
+                Self::checked_from_wide(odd, mant, pow)
+            }
+
+            /// Whether a magnitude is an odd integer, or None when it is not an integer
+            fn odd_integer(mag: u128) -> Option<bool> {
+                if F == 0 { return Some(mag & 1 == 1); }
+                if mag & ((1 << F) - 1) != 0 { return None; }
+                Some((mag >> F) & 1 == 1)
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Exponentials, logarithms and powers for unsigned types, using integer arithmetic only
+/// 
+/// Accuracy is the same as for signed types of the same storage, see `exp_signed`.
+/// Negative results saturate to zero
+macro_rules! exp_unsigned {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// e^self, saturating at MAX
+            pub fn exp(self) -> Self {
+                if !wide::fits(self.bits as u128, F) { return Self::saturated(false); }
+                let (mant, pow) = exp::exp(false, wide::from_bits(self.bits as u128, F));
+                Self::from_wide(false, mant, pow)
+            }
+
+            /// e^self, or None when it exceeds MAX
+            pub fn checked_exp(self) -> Option<Self> {
+                if !wide::fits(self.bits as u128, F) { return None; }
+                let (mant, pow) = exp::exp(false, wide::from_bits(self.bits as u128, F));
+                Self::checked_from_wide(false, mant, pow)
+            }
+
+            /// 2^self, saturating at MAX
+            pub fn exp2(self) -> Self {
+                if !wide::fits(self.bits as u128, F) { return Self::saturated(false); }
+                let (mant, pow) = exp::exp2(false, wide::from_bits(self.bits as u128, F));
+                Self::from_wide(false, mant, pow)
+            }
+
+            /// 2^self, or None when it exceeds MAX
+            pub fn checked_exp2(self) -> Option<Self> {
+                if !wide::fits(self.bits as u128, F) { return None; }
+                let (mant, pow) = exp::exp2(false, wide::from_bits(self.bits as u128, F));
+                Self::checked_from_wide(false, mant, pow)
+            }
+
+            /// Natural logarithm, saturating at MIN/MAX. Values below one give zero
+            pub fn ln(self) -> Self {
+                if self.bits == 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    return Self::MIN;
+                }
+                self.checked_ln().unwrap_or_else(|| Self::saturated(true))
+            }
+
+            /// Natural logarithm, or None when the value is zero or the result is out of range
+            pub fn checked_ln(self) -> Option<Self> {
+                if self.bits == 0 { return None; }
+                let (neg, val) = exp::ln(self.bits as u128, F);
+                Self::checked_from_wide(neg, val, 0)
+            }
+
+            /// Base 2 logarithm, saturating at MIN/MAX. Values below one give zero
+            pub fn log2(self) -> Self {
+                if self.bits == 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    return Self::MIN;
+                }
+                self.checked_log2().unwrap_or_else(|| Self::saturated(true))
+            }
+
+            /// Base 2 logarithm, or None when the value is zero or the result is out of range
+            pub fn checked_log2(self) -> Option<Self> {
+                if self.bits == 0 { return None; }
+                let (neg, val) = exp::log2(self.bits as u128, F);
+                Self::checked_from_wide(neg, val, 0)
+            }
+
+            /// Base 10 logarithm, saturating at MIN/MAX. Values below one give zero
+            pub fn log10(self) -> Self {
+                if self.bits == 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    return Self::MIN;
+                }
+                self.checked_log10().unwrap_or_else(|| Self::saturated(true))
+            }
+
+            /// Base 10 logarithm, or None when the value is zero or the result is out of range
+            pub fn checked_log10(self) -> Option<Self> {
+                if self.bits == 0 { return None; }
+                let (neg, val) = exp::log10(self.bits as u128, F);
+                Self::checked_from_wide(neg, val, 0)
+            }
+
+            /// Raise to an integer power, saturating at MAX.
+            /// 
+            /// Zero to the power of zero is one, and zero to a negative power is MAX
+            pub fn powi(self, n: i32) -> Self {
+                if self.bits == 0 && n < 0 {
+                    raise(StatusFlags::DIVIDE_BY_ZERO);
+                    return Self::MAX;
+                }
+                self.checked_powi(n).unwrap_or_else(|| Self::saturated(false))
+            }
+
+            /// Raise to an integer power, or None when the result is out of range.
+            /// 
+            /// Zero to the power of zero is one, and zero to a negative power is None
+            pub fn checked_powi(self, n: i32) -> Option<Self> {
+                if self.bits == 0 {
+                    return if n > 0 { Some(self) } else if n == 0 { Self::checked_from_wide(false, wide::ONE, 0) } else { None };
+                }
+                let (mant, pow) = exp::powi(self.bits as u128, F, n);
+                Self::checked_from_wide(false, mant, pow)
+            }
+
+            /// Raise to the power of `y`, saturating at MAX.
+            /// 
+            /// Zero to the power of zero is one
+            pub fn powf(self, y: Self) -> Self {
+                self.checked_powf(y).unwrap_or_else(|| Self::saturated(false))
+            }
+
+            /// Raise to the power of `y`, or None when the result is out of range.
+            /// 
+            /// Zero to the power of zero is one
+            pub fn checked_powf(self, y: Self) -> Option<Self> {
+                if self.bits == 0 {
+                    return if y.bits == 0 { Self::checked_from_wide(false, wide::ONE, 0) } else { Some(self) };
+                }
+                let (mant, pow) = exp::powf(self.bits as u128, false, y.bits as u128, F);
+                Self::checked_from_wide(false, mant, pow)
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Value-level rounding, sign and ordering helpers for signed types
+/// 
+/// Like negation, results that cannot be represented saturate: abs(MIN) is MAX, and rounding MAX up
+/// past the largest representable integer gives MAX
+macro_rules! decompose_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Round to a whole number with `mode`, saturating at MIN/MAX
+            fn round_with(self, mode: RoundingMode) -> Self {
+                let (neg, mag) = self.to_parts();
+                Self::from_parts(neg, round_shr(mag, F, neg, mode), 0, mode)
+            }
+
+            /// Largest whole number less than or equal to the value
+            pub fn floor(self) -> Self {
+                self.round_with(RoundingMode::TowardNegative)
+            }
+
+            /// Smallest whole number greater than or equal to the value, saturating at MAX
+            pub fn ceil(self) -> Self {
+                self.round_with(RoundingMode::TowardPositive)
+            }
+
+            /// Nearest whole number, with ties away from zero, saturating at MAX
+            pub fn round(self) -> Self {
+                self.round_with(RoundingMode::NearestAway)
+            }
+
+            /// Nearest whole number, with ties to even, saturating at MAX
+            pub fn round_ties_even(self) -> Self {
+                self.round_with(RoundingMode::NearestEven)
+            }
+
+            /// Whole part, rounding toward zero
+            pub fn trunc(self) -> Self {
+                self.round_with(RoundingMode::TowardZero)
+            }
+
+            /// Fractional part, with the sign of the value. Always exact
+            pub fn fract(self) -> Self {
+                Self { bits: self.bits.wrapping_sub(self.trunc().bits) }
+            }
+
+            /// Absolute value, saturating MIN to MAX
+            pub fn abs(self) -> Self {
+                if (self.bits as $sint) < 0 { -self } else { self }
+            }
+
+            /// One with the sign of the value, or zero. One saturates to MAX when it is out of range
+            pub fn signum(self) -> Self {
+                let (neg, mag) = self.to_parts();
+                if mag == 0 { self } else { Self::from_parts(neg, 1, 0, RoundingMode::NearestEven) }
+            }
+
+            /// The magnitude of the value with the sign of `sign`, saturating at MIN/MAX
+            pub fn copysign(self, sign: Self) -> Self {
+                let (_, mag) = self.to_parts();
+                Self::from_parts((sign.bits as $sint) < 0, mag, F, RoundingMode::NearestEven)
+            }
+
+            /// Absolute difference, saturating at MAX
+            pub fn abs_diff(self, other: Self) -> Self {
+                let diff = (self.bits as $sint).abs_diff(other.bits as $sint);
+                if diff > Self::MAXB { Self::saturated(false) } else { Self { bits: diff } }
+            }
+
+            /// The smaller of two values
+            pub fn min(self, other: Self) -> Self {
+                if other < self { other } else { self }
+            }
+
+            /// The larger of two values
+            pub fn max(self, other: Self) -> Self {
+                if other > self { other } else { self }
+            }
+
+            /// Restrict the value to [min, max].
+            /// 
+            /// Panics when min is greater than max
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                assert!(min <= max, "clamp requires min <= max");
+                if self < min { min } else if self > max { max } else { self }
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Value-level rounding, sign and ordering helpers for unsigned types
+/// 
+/// Results that cannot be represented saturate, so rounding MAX up past the largest representable integer gives MAX
+macro_rules! decompose_unsigned {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// Round to a whole number with `mode`, saturating at MAX
+            fn round_with(self, mode: RoundingMode) -> Self {
+                Self::from_parts(false, round_shr(self.bits as u128, F, false, mode), 0, mode)
+            }
+
+            /// Largest whole number less than or equal to the value
+            pub fn floor(self) -> Self {
+                self.round_with(RoundingMode::TowardNegative)
+            }
+
+            /// Smallest whole number greater than or equal to the value, saturating at MAX
+            pub fn ceil(self) -> Self {
+                self.round_with(RoundingMode::TowardPositive)
+            }
+
+            /// Nearest whole number, with ties away from zero, saturating at MAX
+            pub fn round(self) -> Self {
+                self.round_with(RoundingMode::NearestAway)
+            }
+
+            /// Nearest whole number, with ties to even, saturating at MAX
+            pub fn round_ties_even(self) -> Self {
+                self.round_with(RoundingMode::NearestEven)
+            }
+
+            /// Whole part, rounding toward zero
+            pub fn trunc(self) -> Self {
+                self.round_with(RoundingMode::TowardZero)
+            }
+
+            /// Fractional part. Always exact
+            pub fn fract(self) -> Self {
+                Self { bits: self.bits - self.trunc().bits }
+            }
+
+            /// Absolute value, which is always the value itself
+            pub fn abs(self) -> Self {
+                self
+            }
+
+            /// One, or zero for zero. One saturates to MAX when it is out of range
+            pub fn signum(self) -> Self {
+                if self.bits == 0 { self } else { Self::from_parts(false, 1, 0, RoundingMode::NearestEven) }
+            }
+
+            /// The magnitude of the value with the sign of `sign`, which is always the value itself
+            pub fn copysign(self, _sign: Self) -> Self {
+                self
+            }
+
+            /// Absolute difference
+            pub fn abs_diff(self, other: Self) -> Self {
+                Self { bits: self.bits.abs_diff(other.bits) }
+            }
+
+            /// The smaller of two values
+            pub fn min(self, other: Self) -> Self {
+                if other < self { other } else { self }
+            }
+
+            /// The larger of two values
+            pub fn max(self, other: Self) -> Self {
+                if other > self { other } else { self }
+            }
+
+            /// Restrict the value to [min, max].
+            /// 
+            /// Panics when min is greater than max
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                assert!(min <= max, "clamp requires min <= max");
+                if self < min { min } else if self > max { max } else { self }
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// ULP navigation and distances for signed types, for comparing results against reference values
+macro_rules! ulps_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// The next value up, one grain above. MAX stays at MAX
+            pub fn next_up(self) -> Self {
+                self.checked_add(Self::GRN).unwrap_or(self)
+            }
+
+            /// The next value down, one grain below. MIN stays at MIN
+            pub fn next_down(self) -> Self {
+                self.checked_sub(Self::GRN).unwrap_or(self)
+            }
+
+            /// Number of grains between two values
+            pub fn ulp_distance(self, other: Self) -> u128 {
+                (self.bits as $sint).abs_diff(other.bits as $sint) as u128
+            }
+
+            /// Whether two values are at most `n` grains apart
+            pub fn is_within_ulps(self, other: Self, n: u128) -> bool {
+                self.ulp_distance(other) <= n
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// ULP navigation and distances for unsigned types, for comparing results against reference values
+macro_rules! ulps_unsigned {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> $target<$uint, F> {
+            /// The next value up, one grain above. MAX stays at MAX
+            pub fn next_up(self) -> Self {
+                self.checked_add(Self::GRN).unwrap_or(self)
+            }
+
+            /// The next value down, one grain below. Zero stays at zero
+            pub fn next_down(self) -> Self {
+                self.checked_sub(Self::GRN).unwrap_or(self)
+            }
+
+            /// Number of grains between two values
+            pub fn ulp_distance(self, other: Self) -> u128 {
+                self.bits.abs_diff(other.bits) as u128
+            }
+
+            /// Whether two values are at most `n` grains apart
+            pub fn is_within_ulps(self, other: Self, n: u128) -> bool {
+                self.ulp_distance(other) <= n
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Correctly rounded conversions between a type and an IEEE float given by its bits type and format widths.
+/// 
+/// Used for the optional f16 and f128 primitives
+macro_rules! float_casts_ieee {
+    ($target:ident, $uint:ty, $float:ident, $fbits:ty, $man:expr, $exp:expr) => {
+        impl<const F: u32> From<$target<$uint, F>> for $float {
+            /// Convert to a float, correctly rounded (ties to even). Values past its largest finite value become infinite
+            fn from(val: $target<$uint, F>) -> Self {
+                $float::from_bits(val.to_float_bits($man, $exp) as $fbits)
+            }
+        }
+
+        impl<const F: u32> From<$float> for $target<$uint, F> {
+            /// Convert from a float, correctly rounded (ties to even).
+            /// 
+            /// Values out of range and infinities saturate at MIN/MAX, and NaN gives zero
+            fn from(val: $float) -> Self {
+                Self::from_float_bits(val.to_bits() as u128, $man, $exp)
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Fallible conversions from floats, which report NaN, out-of-range and (when strict) inexact values
+/// 
+/// Floats already convert through the saturating `From`, so these go through `FromChecked` rather than `TryFrom`
+macro_rules! from_checked_float {
+    ($target:ident, $uint:ty, $float:ident, $man:expr, $exp:expr) => {
+        impl<const F: u32> FromChecked<$float> for $target<$uint, F> {
+            fn from_checked(val: $float) -> Result<Self, CertumError> {
+                Self::try_from_float_bits(val.to_bits() as u128, $man, $exp, false)
+            }
+
+            fn from_strict(val: $float) -> Result<Self, CertumError> {
+                Self::try_from_float_bits(val.to_bits() as u128, $man, $exp, true)
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Fallible conversions from whole numbers, which report out-of-range values
+/// 
+/// Takes the signed integers, then the unsigned ones other than the storage's own,
+/// which already converts through `From` and so only gets `FromChecked`
+macro_rules! try_from_int {
+    ($target:ident, $uint:ty; $($sint:ty),*; $($other:ty),*) => {
+        impl<const F: u32> FromChecked<$uint> for $target<$uint, F> {
+            fn from_checked(val: $uint) -> Result<Self, CertumError> {
+                Self::try_from_parts(false, val as u128, 0, false)
+            }
+
+            fn from_strict(val: $uint) -> Result<Self, CertumError> {
+                Self::try_from_parts(false, val as u128, 0, true)
+            }
+        }
+
+        $(
+            impl<const F: u32> FromChecked<$sint> for $target<$uint, F> {
+                fn from_checked(val: $sint) -> Result<Self, CertumError> {
+                    Self::try_from_parts(val < 0, val.unsigned_abs() as u128, 0, false)
+                }
+
+                fn from_strict(val: $sint) -> Result<Self, CertumError> {
+                    Self::try_from_parts(val < 0, val.unsigned_abs() as u128, 0, true)
+                }
+            }
+
+            impl<const F: u32> TryFrom<$sint> for $target<$uint, F> {
+                type Error = CertumError;
+                /// Convert from a whole number. Fails with Overflow when it is out of range
+                fn try_from(val: $sint) -> Result<Self, CertumError> {
+                    Self::from_checked(val)
+                }
+            }
+        )*
+
+        $(
+            impl<const F: u32> FromChecked<$other> for $target<$uint, F> {
+                fn from_checked(val: $other) -> Result<Self, CertumError> {
+                    Self::try_from_parts(false, val as u128, 0, false)
+                }
+
+                fn from_strict(val: $other) -> Result<Self, CertumError> {
+                    Self::try_from_parts(false, val as u128, 0, true)
+                }
+            }
+
+            impl<const F: u32> TryFrom<$other> for $target<$uint, F> {
+                type Error = CertumError;
+                /// Convert from a whole number. Fails with Overflow when it is out of range
+                fn try_from(val: $other) -> Result<Self, CertumError> {
+                    Self::from_checked(val)
+                }
+            }
+        )*
+    };
+}
+
+#[macro_export]
+/// Fallible conversions between layouts of any width, fraction and signedness, which report out-of-range values
+macro_rules! from_checked_layouts {
+    ($target:ident, $uint:ty; $($source:ident $suint:ty),*) => {
+        $(
+            impl<const A: u32, const B: u32> FromChecked<$source<$suint, A>> for $target<$uint, B> {
+                fn from_checked(val: $source<$suint, A>) -> Result<Self, CertumError> {
+                    let (neg, mag) = val.to_parts();
+                    Self::try_from_parts(neg, mag, A, false)
+                }
+
+                fn from_strict(val: $source<$suint, A>) -> Result<Self, CertumError> {
+                    let (neg, mag) = val.to_parts();
+                    Self::try_from_parts(neg, mag, A, true)
+                }
+            }
+        )*
+    };
+}
+
+#[macro_export]
+/// Remainder for types
+macro_rules! rem_same {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> Rem for $target<$uint, F> {
+            type Output = Self;
+            /// Remainder of a truncated division. Always exact, and takes the sign of the dividend.
+            /// 
+            /// The remainder of a division by zero is zero
+            fn rem(self, rhs: Self) -> Self {
+                if rhs.bits == 0 { return Self { bits: 0 }; }
+                // Both operands share a scale, so the remainder of the raw bits is the remainder of the values
+                let bits = <$sint>::wrapping_rem(self.bits as $sint, rhs.bits as $sint);
+                Self { bits: bits as $uint }
+            }
+        }
+
+        impl<const F: u32> RemAssign for $target<$uint, F> {
+            fn rem_assign(&mut self, rhs: Self) {
+                *self = *self % rhs
+            }
+        }
+    }
+}
+
+#[macro_export]
+/// Convert quire to 128-bit types
+macro_rules! from_u256 {
+    ($target:ident) => {
+        impl<const F: u32> From<u256> for $target<u128, F> {
+            /// Convert from a quire to a 128-bit type.
+            /// 
+            /// Equivalent to using `as` to cast from bigger int to a smaller int
+            fn from(val: u256) -> Self {
+                Self::from(val.bits[1])
             }
         }
     }
@@ -356,47 +2501,47 @@ macro_rules! from_u256 {
 /// General float casts
 macro_rules! float_casts {
     ($target:ident, $uint:ty) => {
-        impl From<&$target> for f64 {
+        impl<const F: u32> From<&$target<$uint, F>> for f64 {
             /// Convert to a 64-bit Float
-            fn from(val: &$target) -> Self {
+            fn from(val: &$target<$uint, F>) -> Self {
                 f64::from(*val)
             }
         }
 
-        impl From<$target> for f32 {
-            /// Convert to a 32-bit Float
-            fn from(val: $target) -> Self {
-                f64::from(val) as f32
+        impl<const F: u32> From<$target<$uint, F>> for f32 {
+            /// Convert to a 32-bit Float, correctly rounded (ties to even) without rounding through f64 first.
+            /// 
+            /// Values past f32::MAX become infinite
+            fn from(val: $target<$uint, F>) -> Self {
+                f32::from_bits(val.to_float_bits(23, 8) as u32)
             }
         }
 
-        impl From<&$target> for f32 {
-            /// Convert to a 32-bit Float
-            fn from(val: &$target) -> Self {
-                f64::from(*val) as f32
+        impl<const F: u32> From<&$target<$uint, F>> for f32 {
+            /// Convert to a 32-bit Float, correctly rounded (ties to even)
+            fn from(val: &$target<$uint, F>) -> Self {
+                f32::from(*val)
             }
         }
 
-        impl From<$uint> for $target {
-            /// Convert from a UInt
-            fn from(bits: $uint) -> Self {
-                $target::from(bits as f64)
+        impl<const F: u32> From<$uint> for $target<$uint, F> {
+            /// Convert from a UInt holding a whole number, saturating values out of range
+            fn from(val: $uint) -> Self {
+                Self::from_parts(false, val as u128, 0, RoundingMode::NearestEven)
             }
         }
 
-        impl From<&$uint> for $target {
-            /// Convert from a UInt
+        impl<const F: u32> From<&$uint> for $target<$uint, F> {
+            /// Convert from a UInt holding a whole number, saturating values out of range
             fn from(val: &$uint) -> Self {
-                $target::from(*val as f64)
+                Self::from(*val)
             }
         }
 
-        impl From<f32> for $target {
-            /// Convert from a 32-Bit Float
+        impl<const F: u32> From<f32> for $target<$uint, F> {
+            /// Convert from a 32-Bit Float, correctly rounded (ties to even), like From<f64>
             fn from(val: f32) -> Self {
-                if val == f32::INFINITY { return $target::MAX }
-                if val == f32::NEG_INFINITY { return $target::MIN }
-                $target::from(val as f64)
+                Self::from_float_bits(val.to_bits() as u128, 23, 8)
             }
         }
     }
@@ -421,80 +2566,545 @@ macro_rules! float_casts {
 // }
 
 #[macro_export]
-/// Float conversion for signed certums
-macro_rules! float_convert_sc {
-    ($target:ident, $uint:ty, $sint:ty, $scale:expr, $dec:expr, $lim:expr) => {
-        impl From<$target> for f64 {
-            /// Convert to a 64-bit Float
-            fn from(val: $target) -> Self {
-                (val.bits as $sint) as f64 / (<$uint>::from(1u8) << ($scale - $dec)) as f64
+/// Float conversion for signed types of any layout, by scaling with 2 ^ FRC
+macro_rules! float_scaled_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> From<$target<$uint, F>> for f64 {
+            /// Convert to a 64-bit Float, correctly rounded (ties to even)
+            fn from(val: $target<$uint, F>) -> Self {
+                f64::from_bits(val.to_float_bits(52, 11) as u64)
             }
         }
 
-        impl From<f64> for $target {
-            /// Convert from a 64-bit Float
+        impl<const F: u32> From<f64> for $target<$uint, F> {
+            /// Convert from a 64-bit Float, correctly rounded (ties to even).
+            /// 
+            /// Subnormals and both zeros round like any other value, values out of range
+            /// and infinities saturate at MIN/MAX, and NaN gives zero
             fn from(val: f64) -> Self {
-                if val == f64::INFINITY { return $target::MAX }
-                if val == f64::NEG_INFINITY { return $target::MIN }
-                let (sgn, int, frc) = f64_split(val.clamp($target::MINF, $target::MAXF));
-                let sign = (sgn as $uint) << ($scale - 1);
-                // Combine integer and fraction parts
-                let combined = ((int as $uint) << ($scale - $dec)) | $target::u64_round(frc >> $dec);
-                // Clamp off for sign and add sign bit
-                // 2^(bits - 1) - 1
-                let mut checked = (combined & $lim);
-                if sign > 0 { checked = !checked + 1 }
-                $target { bits: sign | checked }
+                Self::from_float_bits(val.to_bits() as u128, 52, 11)
             }
         }
     };
 }
 
 #[macro_export]
-/// Float conversion for unsigned certums
-macro_rules! float_convert_uc {
-    ($target:ident, $uint:ty, $scale:expr, $dec:expr, $lim:expr) => {
-        impl From<$target> for f64 {
-            /// Convert to a 64-bit Float
-            fn from(val: $target) -> Self {
-                let (int, frc) = val.components();
-                let float_frc = (frc as f64) / f64::powi(2f64, $scale); // MSB-Shifted fraction / 2^Bits
-                (int as f64) + float_frc // Add integer and fraction
+/// Float conversion for unsigned types of any layout, by scaling with 2 ^ FRC
+macro_rules! float_scaled_unsigned {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> From<$target<$uint, F>> for f64 {
+            /// Convert to a 64-bit Float, correctly rounded (ties to even)
+            fn from(val: $target<$uint, F>) -> Self {
+                f64::from_bits(val.to_float_bits(52, 11) as u64)
             }
         }
 
-        impl From<f64> for $target {
-            /// Convert from a 64-bit Float
+        impl<const F: u32> From<f64> for $target<$uint, F> {
+            /// Convert from a 64-bit Float, correctly rounded (ties to even).
+            /// 
+            /// Subnormals and both zeros round like any other value, values out of range
+            /// and infinities saturate at MIN/MAX, and NaN gives zero
             fn from(val: f64) -> Self {
-                let (_sgn, int, frc) = f64_split(val.clamp($target::MINF, $target::MAXF));
-                // Combine integer and fraction parts
-                let bits = ((int as $uint) << ($scale - $dec)) | $target::u64_round(frc >> $dec);
-                $target { bits }
+                Self::from_float_bits(val.to_bits() as u128, 52, 11)
             }
         }
     };
 }
 
 #[macro_export]
-// Float conversion for types > 64 bit
-macro_rules! lossy_float {
-    ($target:ident, $fromtype:ident, $shift:expr) => {
-        impl From<$target> for f64 {
-            /// Convert to a 64-bit Float
-            fn from(val: $target) -> Self {
-                f64::from($fromtype::from(val))
+/// Exact decimal Display and FromStr for signed types
+macro_rules! decimal_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> Display for $target<$uint, F> {
+            /// Print the exact decimal value, rounded to nearest (ties to even) when a precision is given
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let val = self.bits as $sint;
+                write_decimal(f, val < 0, val.unsigned_abs() as u128, F)
             }
         }
 
-        impl From<f64> for $target {
-            /// Convert from a 64-bit Float
-            fn from(val: f64) -> Self {
-                $target::from($fromtype::from(val))
+        impl<const F: u32> FromStr for $target<$uint, F> {
+            type Err = ParseCertumError;
+            /// Parse a decimal string into the nearest value, ties to even
+            fn from_str(src: &str) -> Result<Self, Self::Err> {
+                let (neg, mag) = parse_decimal(src, F, <$sint>::MAX as u128, <$sint>::MIN.unsigned_abs() as u128)?;
+                let bits = mag as $uint;
+                Ok(Self { bits: if neg { bits.wrapping_neg() } else { bits } })
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Exact decimal Display and FromStr for unsigned types
+macro_rules! decimal_unsigned {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> Display for $target<$uint, F> {
+            /// Print the exact decimal value, rounded to nearest (ties to even) when a precision is given
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write_decimal(f, false, self.bits as u128, F)
+            }
+        }
+
+        impl<const F: u32> FromStr for $target<$uint, F> {
+            type Err = ParseCertumError;
+            /// Parse a decimal string into the nearest value, ties to even
+            fn from_str(src: &str) -> Result<Self, Self::Err> {
+                let (_, mag) = parse_decimal(src, F, <$uint>::MAX as u128, 0)?;
+                Ok(Self { bits: mag as $uint })
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Exact conversion to and from ALN for signed types
+macro_rules! aln_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> From<$target<$uint, F>> for ALN {
+            /// Convert to the exact decimal value
+            fn from(val: $target<$uint, F>) -> Self {
+                let bits = val.bits as $sint;
+                ALN::from_bits(bits < 0, bits.unsigned_abs() as u128, F)
+            }
+        }
+
+        impl<const F: u32> $target<$uint, F> {
+            /// Convert from an ALN with the given rounding, saturating values out of range
+            pub fn from_aln(val: &ALN, mode: RoundingMode) -> Self {
+                Self::from_aln_report(val, mode).0
+            }
+
+            /// Convert from an ALN with the given rounding, and report the rounding error
+            pub fn from_aln_report(val: &ALN, mode: RoundingMode) -> (Self, RoundingReport) {
+                let (neg, mag, saturated) = val.to_bits(F, <$sint>::MAX as u128, <$sint>::MIN.unsigned_abs() as u128, mode);
+                let bits = mag as $uint;
+                let res = Self { bits: if neg { bits.wrapping_neg() } else { bits } };
+                let report = val.report(ALN::from(res), F, saturated);
+                if saturated {
+                    raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                } else if !report.is_exact() {
+                    raise_rounded(res.bits == 0);
+                }
+                (res, report)
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Exact conversion to and from ALN for unsigned types
+macro_rules! aln_unsigned {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> From<$target<$uint, F>> for ALN {
+            /// Convert to the exact decimal value
+            fn from(val: $target<$uint, F>) -> Self {
+                ALN::from_bits(false, val.bits as u128, F)
+            }
+        }
+
+        impl<const F: u32> $target<$uint, F> {
+            /// Convert from an ALN with the given rounding, saturating values out of range
+            pub fn from_aln(val: &ALN, mode: RoundingMode) -> Self {
+                Self::from_aln_report(val, mode).0
+            }
+
+            /// Convert from an ALN with the given rounding, and report the rounding error
+            pub fn from_aln_report(val: &ALN, mode: RoundingMode) -> (Self, RoundingReport) {
+                let (_, mag, saturated) = val.to_bits(F, <$uint>::MAX as u128, 0, mode);
+                let res = Self { bits: mag as $uint };
+                let report = val.report(ALN::from(res), F, saturated);
+                if saturated {
+                    raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                } else if !report.is_exact() {
+                    raise_rounded(res.bits == 0);
+                }
+                (res, report)
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Exact Quire accumulation for signed types
+macro_rules! quire_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> Quire<$target<$uint, F>> {
+            /// Add the exact product of two values
+            pub fn add_product(&mut self, lhs: $target<$uint, F>, rhs: $target<$uint, F>) {
+                let product = i256::from_mul(lhs.bits as $sint as i128, rhs.bits as $sint as i128);
+                self.add_term(u256::from(product), product.is_negative());
+            }
+
+            /// Subtract the exact product of two values
+            pub fn sub_product(&mut self, lhs: $target<$uint, F>, rhs: $target<$uint, F>) {
+                let product = i256::from_mul(lhs.bits as $sint as i128, rhs.bits as $sint as i128);
+                self.sub_term(u256::from(product), product.is_negative());
+            }
+
+            /// Add a single value exactly
+            pub fn add_value(&mut self, val: $target<$uint, F>) {
+                let term = i256::from(val.bits as $sint as i128) << F as u128;
+                self.add_term(u256::from(term), term.is_negative());
+            }
+
+            /// Subtract a single value exactly
+            pub fn sub_value(&mut self, val: $target<$uint, F>) {
+                let term = i256::from(val.bits as $sint as i128) << F as u128;
+                self.sub_term(u256::from(term), term.is_negative());
+            }
+
+            /// Round the sum once to the nearest value (ties to even), saturating values out of range
+            pub fn value(&self) -> $target<$uint, F> {
+                self.value_round(RoundingMode::NearestEven)
+            }
+
+            /// Round the sum once with the given rounding, saturating values out of range
+            pub fn value_round(&self, mode: RoundingMode) -> $target<$uint, F> {
+                let (neg, mag, _) = self.to_bits(F, <$sint>::MAX as u128, <$sint>::MIN.unsigned_abs() as u128, mode);
+                let bits = mag as $uint;
+                $target { bits: if neg { bits.wrapping_neg() } else { bits } }
+            }
+
+            /// Dot product of two slices, exact until a single final rounding.
+            /// 
+            /// Extra elements of the longer slice are ignored
+            pub fn dot(lhs: &[$target<$uint, F>], rhs: &[$target<$uint, F>]) -> $target<$uint, F> {
+                let mut quire = Self::new();
+                for (&l, &r) in lhs.iter().zip(rhs) {
+                    quire.add_product(l, r);
+                }
+                quire.value()
+            }
+        }
+
+        impl<const F: u32> $target<$uint, F> {
+            /// Fused multiply-add, `self * a + b` rounded once to the nearest value (ties to even) and saturating at MIN/MAX
+            pub fn mul_add(self, a: Self, b: Self) -> Self {
+                self.mul_add_round(a, b, RoundingMode::NearestEven)
+            }
+
+            /// Fused multiply-subtract, `self * a - b` rounded once to the nearest value (ties to even) and saturating at MIN/MAX
+            pub fn mul_sub(self, a: Self, b: Self) -> Self {
+                self.mul_sub_round(a, b, RoundingMode::NearestEven)
+            }
+
+            /// Fused multiply-add, `self * a + b` rounded once with `mode` and saturating at MIN/MAX
+            pub fn mul_add_round(self, a: Self, b: Self, mode: RoundingMode) -> Self {
+                let mut quire = Quire::<Self>::new();
+                quire.add_product(self, a);
+                quire.add_value(b);
+                quire.value_round(mode)
+            }
+
+            /// Fused multiply-subtract, `self * a - b` rounded once with `mode` and saturating at MIN/MAX
+            pub fn mul_sub_round(self, a: Self, b: Self, mode: RoundingMode) -> Self {
+                let mut quire = Quire::<Self>::new();
+                quire.add_product(self, a);
+                quire.sub_value(b);
+                quire.value_round(mode)
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Exact Quire accumulation for unsigned types
+macro_rules! quire_unsigned {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> Quire<$target<$uint, F>> {
+            /// Add the exact product of two values
+            pub fn add_product(&mut self, lhs: $target<$uint, F>, rhs: $target<$uint, F>) {
+                self.add_term(u256::from_mul(lhs.bits as u128, rhs.bits as u128), false);
+            }
+
+            /// Subtract the exact product of two values
+            pub fn sub_product(&mut self, lhs: $target<$uint, F>, rhs: $target<$uint, F>) {
+                self.sub_term(u256::from_mul(lhs.bits as u128, rhs.bits as u128), false);
+            }
+
+            /// Add a single value exactly
+            pub fn add_value(&mut self, val: $target<$uint, F>) {
+                self.add_term(u256::from(val.bits as u128) << F as u128, false);
+            }
+
+            /// Subtract a single value exactly
+            pub fn sub_value(&mut self, val: $target<$uint, F>) {
+                self.sub_term(u256::from(val.bits as u128) << F as u128, false);
+            }
+
+            /// Round the sum once to the nearest value (ties to even), saturating values out of range
+            pub fn value(&self) -> $target<$uint, F> {
+                self.value_round(RoundingMode::NearestEven)
+            }
+
+            /// Round the sum once with the given rounding, saturating values out of range
+            pub fn value_round(&self, mode: RoundingMode) -> $target<$uint, F> {
+                let (_, mag, _) = self.to_bits(F, <$uint>::MAX as u128, 0, mode);
+                $target { bits: mag as $uint }
+            }
+
+            /// Dot product of two slices, exact until a single final rounding.
+            /// 
+            /// Extra elements of the longer slice are ignored
+            pub fn dot(lhs: &[$target<$uint, F>], rhs: &[$target<$uint, F>]) -> $target<$uint, F> {
+                let mut quire = Self::new();
+                for (&l, &r) in lhs.iter().zip(rhs) {
+                    quire.add_product(l, r);
+                }
+                quire.value()
+            }
+        }
+
+        impl<const F: u32> $target<$uint, F> {
+            /// Fused multiply-add, `self * a + b` rounded once to the nearest value (ties to even) and saturating at MIN/MAX
+            pub fn mul_add(self, a: Self, b: Self) -> Self {
+                self.mul_add_round(a, b, RoundingMode::NearestEven)
+            }
+
+            /// Fused multiply-subtract, `self * a - b` rounded once to the nearest value (ties to even) and saturating at MIN/MAX
+            pub fn mul_sub(self, a: Self, b: Self) -> Self {
+                self.mul_sub_round(a, b, RoundingMode::NearestEven)
+            }
+
+            /// Fused multiply-add, `self * a + b` rounded once with `mode` and saturating at MIN/MAX
+            pub fn mul_add_round(self, a: Self, b: Self, mode: RoundingMode) -> Self {
+                let mut quire = Quire::<Self>::new();
+                quire.add_product(self, a);
+                quire.add_value(b);
+                quire.value_round(mode)
+            }
+
+            /// Fused multiply-subtract, `self * a - b` rounded once with `mode` and saturating at MIN/MAX
+            pub fn mul_sub_round(self, a: Self, b: Self, mode: RoundingMode) -> Self {
+                let mut quire = Quire::<Self>::new();
+                quire.add_product(self, a);
+                quire.sub_value(b);
+                quire.value_round(mode)
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// Raw bit and fraction width access through the FixedPoint trait
+macro_rules! fixed_point {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> FixedPoint for $target<$uint, F> {
+            type Bits = $uint;
+            const FRAC: u32 = F;
+
+            fn from_bits(bits: $uint) -> Self {
+                Self { bits }
+            }
+
+            fn to_bits(self) -> $uint {
+                self.bits
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// num-traits implementations shared by signed and unsigned types
+/// 
+/// Arithmetic follows the operators and the checked and saturating forms, so it rounds to nearest (ties to even).
+/// Conversions from primitives go through FromChecked and return None where it fails,
+/// and conversions to integers truncate toward zero like the float implementations of num-traits
+macro_rules! num_traits_common {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> Zero for $target<$uint, F> {
+            fn zero() -> Self {
+                Self { bits: 0 }
+            }
+
+            fn is_zero(&self) -> bool {
+                self.bits == 0
+            }
+        }
+
+        impl<const F: u32> One for $target<$uint, F> {
+            /// One, saturating to MAX for layouts that cannot hold it
+            fn one() -> Self {
+                Self::from_int(1)
+            }
+        }
+
+        impl<const F: u32> Num for $target<$uint, F> {
+            type FromStrRadixErr = ParseCertumError;
+            /// Parse a decimal string. Other radixes are rejected as invalid digits
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, ParseCertumError> {
+                if radix != 10 { return Err(ParseCertumError::new(ParseErrorKind::InvalidDigit)); }
+                str.parse()
+            }
+        }
+
+        impl<const F: u32> Bounded for $target<$uint, F> {
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl<const F: u32> FromPrimitive for $target<$uint, F> {
+            fn from_i64(n: i64) -> Option<Self> {
+                Self::from_checked(n).ok()
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                Self::from_checked(n).ok()
+            }
+
+            fn from_i128(n: i128) -> Option<Self> {
+                Self::from_checked(n).ok()
+            }
+
+            fn from_u128(n: u128) -> Option<Self> {
+                Self::from_checked(n).ok()
+            }
+
+            fn from_f32(n: f32) -> Option<Self> {
+                Self::from_checked(n).ok()
+            }
+
+            fn from_f64(n: f64) -> Option<Self> {
+                Self::from_checked(n).ok()
+            }
+        }
+
+        impl<const F: u32> ToPrimitive for $target<$uint, F> {
+            fn to_i64(&self) -> Option<i64> {
+                i64::try_from(self.to_int(RoundingMode::TowardZero)).ok()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                u64::try_from(self.to_int(RoundingMode::TowardZero)).ok()
+            }
+
+            fn to_i128(&self) -> Option<i128> {
+                i128::try_from(self.to_int(RoundingMode::TowardZero)).ok()
+            }
+
+            fn to_u128(&self) -> Option<u128> {
+                u128::try_from(self.to_int(RoundingMode::TowardZero)).ok()
+            }
+
+            fn to_f32(&self) -> Option<f32> {
+                Some(f32::from(self))
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                Some(f64::from(self))
+            }
+        }
+
+        impl<const F: u32> num_traits::NumCast for $target<$uint, F> {
+            /// Convert any primitive, exactly for whole numbers and through f64 otherwise
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                let float = n.to_f64()?;
+                if float.fract() == 0.0 {
+                    // Whole numbers past 2^53 would lose bits through f64
+                    if let Some(int) = n.to_i128() { return Self::from_checked(int).ok(); }
+                    if let Some(int) = n.to_u128() { return Self::from_checked(int).ok(); }
+                }
+                Self::from_checked(float).ok()
+            }
+        }
+
+        impl<const F: u32> CheckedAdd for $target<$uint, F> {
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                Self::checked_add(*self, *v)
+            }
+        }
+
+        impl<const F: u32> CheckedSub for $target<$uint, F> {
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                Self::checked_sub(*self, *v)
+            }
+        }
+
+        impl<const F: u32> CheckedMul for $target<$uint, F> {
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                Self::checked_mul(*self, *v)
+            }
+        }
+
+        impl<const F: u32> CheckedDiv for $target<$uint, F> {
+            fn checked_div(&self, v: &Self) -> Option<Self> {
+                Self::checked_div(*self, *v)
+            }
+        }
+
+        impl<const F: u32> Saturating for $target<$uint, F> {
+            fn saturating_add(self, v: Self) -> Self {
+                Self::saturating_add(self, v)
+            }
+
+            fn saturating_sub(self, v: Self) -> Self {
+                Self::saturating_sub(self, v)
+            }
+        }
+
+        impl<const F: u32> SaturatingAdd for $target<$uint, F> {
+            fn saturating_add(&self, v: &Self) -> Self {
+                Self::saturating_add(*self, *v)
+            }
+        }
+
+        impl<const F: u32> SaturatingSub for $target<$uint, F> {
+            fn saturating_sub(&self, v: &Self) -> Self {
+                Self::saturating_sub(*self, *v)
+            }
+        }
+
+        impl<const F: u32> SaturatingMul for $target<$uint, F> {
+            fn saturating_mul(&self, v: &Self) -> Self {
+                Self::saturating_mul(*self, *v)
+            }
+        }
+    };
+}
+
+#[macro_export]
+/// num-traits Signed for signed types
+macro_rules! num_traits_signed {
+    ($target:ident, $uint:ty, $sint:ty) => {
+        impl<const F: u32> Signed for $target<$uint, F> {
+            fn abs(&self) -> Self {
+                Self::abs(*self)
+            }
+
+            /// The positive difference, or zero when `other` is larger
+            fn abs_sub(&self, other: &Self) -> Self {
+                if *self <= *other { Self { bits: 0 } } else { *self - *other }
+            }
+
+            fn signum(&self) -> Self {
+                Self::signum(*self)
+            }
+
+            fn is_positive(&self) -> bool {
+                (self.bits as $sint) > 0
+            }
+
+            fn is_negative(&self) -> bool {
+                (self.bits as $sint) < 0
             }
         }
     };
 }
 
+#[macro_export]
+/// num-traits Unsigned for unsigned types
+macro_rules! num_traits_unsigned {
+    ($target:ident, $uint:ty) => {
+        impl<const F: u32> Unsigned for $target<$uint, F> {}
+    };
+}
+
 // impl Add for $target {
 //     type Output = $target;
 //     fn add(self, rhs: Self) -> Self::Output {
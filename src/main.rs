@@ -3,7 +3,7 @@
 use {
     certums::{
         c8, c16, c32, c64, c128, 
-        from_direct, from_left_shift, from_right_shift, 
+        from_direct, from_storage, from_storage_signed, 
         u256, uc8, uc16, uc32, uc64, uc128, 
         utils::{QuickLog, display_certums},
         ALN
@@ -39,6 +39,6 @@ fn main() {
     // c1.log_bits();
     // c1.log_value();
 
-    let aln = ALN::from("1234567890.0987654321");
+    let _aln = ALN::from("1234567890.0987654321");
 
 }
\ No newline at end of file
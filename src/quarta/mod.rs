@@ -0,0 +1,13 @@
+// Signed Quartas
+pub mod quarta8;
+pub mod quarta16;
+pub mod quarta32;
+pub mod quarta64;
+pub mod quarta128;
+
+// Unsigned Quartas
+pub mod u_quarta8;
+pub mod u_quarta16;
+pub mod u_quarta32;
+pub mod u_quarta64;
+pub mod u_quarta128;
\ No newline at end of file
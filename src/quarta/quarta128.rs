@@ -0,0 +1,30 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 128-bit Signed Quarta
+/// 
+/// 1 Sign bit, 31 Integer bits, 96 Fraction bits
+/// 
+/// Minimum Decimal: -2147483648
+/// 
+/// Maximum Decimal: 2147483647.999999999999999999999999999987378225516463811113412342955475420325228697038255631923675537109375
+pub type q128 = Fixed<u128, 96>;
+
+impl q128 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.14159265358979323846264338327933331564396202138222664694555685827026536571793258190155029296875
+    /// Exact:   3.141592653589793238462643383279
+    /// 
+    /// Digits of Accuracy: 31
+    /// 
+    pub const PI: Self = Self { bits: 0x3243F6A8885A308D313198A2E };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.71828182845904523536028747134492392784187395353596994329592462236178107559680938720703125
+    /// Exact:   2.7182818284590452353602874713
+    /// 
+    /// Digits of Accuracy: 29
+    /// 
+    pub const E: Self = Self { bits: 0x2B7E151628AED2A6ABF715880 };
+}
\ No newline at end of file
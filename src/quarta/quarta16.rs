@@ -0,0 +1,30 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 16-bit Signed Quarta
+/// 
+/// 1 Sign bit, 3 Integer bits, 12 Fraction bits
+/// 
+/// Minimum Decimal: -8
+/// 
+/// Maximum Decimal: 7.999755859375
+pub type q16 = Fixed<u16, 12>;
+
+impl q16 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.141357421875
+    /// Exact:   3.141
+    /// 
+    /// Digits of Accuracy: 4
+    /// 
+    pub const PI: Self = Self { bits: 0x3243 };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.71826171875
+    /// Exact:   2.7182
+    /// 
+    /// Digits of Accuracy: 5
+    /// 
+    pub const E: Self = Self { bits: 0x2B7E };
+}
\ No newline at end of file
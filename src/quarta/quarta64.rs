@@ -0,0 +1,30 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 64-bit Signed Quarta
+/// 
+/// 1 Sign bit, 15 Integer bits, 48 Fraction bits
+/// 
+/// Minimum Decimal: -32768
+/// 
+/// Maximum Decimal: 32767.999999999999996447286321199499070644378662109375
+pub type q64 = Fixed<u64, 48>;
+
+impl q64 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.141592653589793115997963468544185161590576171875
+    /// Exact:   3.141592653589793
+    /// 
+    /// Digits of Accuracy: 16
+    /// 
+    pub const PI: Self = Self { bits: 0x3243F6A8885A3 };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.718281828459044646706388448365032672882080078125
+    /// Exact:   2.71828182845904
+    /// 
+    /// Digits of Accuracy: 15
+    /// 
+    pub const E: Self = Self { bits: 0x2B7E151628AED };
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+use crate::fixed::signed::Fixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 8-bit Signed Quarta
+/// 
+/// 1 Sign bit, 1 Integer bit, 6 Fraction bits
+/// 
+/// Minimum Decimal: -2
+/// 
+/// Maximum Decimal: 1.984375
+/// 
+/// Same layout as the 8-bit Signed Certum, so both names refer to the same type
+pub type q8 = Fixed<u8, 6>;
\ No newline at end of file
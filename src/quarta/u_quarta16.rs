@@ -0,0 +1,30 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 16-bit Unsigned Quarta
+/// 
+/// 4 Integer bits, 12 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 15.999755859375
+pub type uq16 = UFixed<u16, 12>;
+
+impl uq16 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.141357421875
+    /// Exact:   3.141
+    /// 
+    /// Digits of Accuracy: 4
+    /// 
+    pub const PI: Self = Self { bits: 0x3243 };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.71826171875
+    /// Exact:   2.7182
+    /// 
+    /// Digits of Accuracy: 5
+    /// 
+    pub const E: Self = Self { bits: 0x2B7E };
+}
\ No newline at end of file
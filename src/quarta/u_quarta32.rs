@@ -0,0 +1,30 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 32-bit Unsigned Quarta
+/// 
+/// 8 Integer bits, 24 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 255.999999940395355224609375
+pub type uq32 = UFixed<u32, 24>;
+
+impl uq32 {
+    /// Archimedes' Constant - π
+    /// 
+    /// Decimal: 3.14159262180328369140625
+    /// Exact:   3.1415926
+    /// 
+    /// Digits of Accuracy: 8
+    /// 
+    pub const PI: Self = Self { bits: 0x3243F6A };
+    /// Eulers' Number - e
+    /// 
+    /// Decimal: 2.718281805515289306640625
+    /// Exact:   2.7182818
+    /// 
+    /// Digits of Accuracy: 8
+    /// 
+    pub const E: Self = Self { bits: 0x2B7E151 };
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+use crate::fixed::unsigned::UFixed;
+
+#[expect(non_camel_case_types)]
+/// Define a generic 8-bit Unsigned Quarta
+/// 
+/// 2 Integer bits, 6 Fraction bits
+/// 
+/// Minimum Decimal: 0
+/// 
+/// Maximum Decimal: 3.984375
+/// 
+/// Same layout as the 8-bit Unsigned Certum, so both names refer to the same type
+pub type uq8 = UFixed<u8, 6>;
\ No newline at end of file
@@ -0,0 +1,105 @@
+use std::{cmp::Ordering, marker::PhantomData};
+use super::{quire256::u256, i256::i256};
+use crate::{rounding::RoundingMode, flags::{StatusFlags, raise, raise_rounded}};
+
+#[derive(Copy, Clone, Debug)]
+/// An exact accumulator for sums of products, like the quire of posit arithmetic.
+///
+/// Products of two values with `F` fraction bits are kept exactly with `2F` fraction bits
+/// in a 512-bit two's complement register, and only rounded once when read back.
+/// A product of two 128-bit values needs at most 256 bits, so the register holds the exact sum
+/// of at least 2^255 products (or added values) of any width before it can wrap.
+/// Narrower widths leave even more headroom.
+pub struct Quire<T> {
+    lo: u256,
+    hi: u256,
+    _value: PhantomData<T>,
+}
+
+impl<T> Default for Quire<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Quire<T> {
+    /// Create an empty Quire, holding zero
+    pub const fn new() -> Self {
+        Self { lo: u256::MIN, hi: u256::MIN, _value: PhantomData }
+    }
+
+    /// Reset the Quire to zero
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Whether the Quire holds exactly zero
+    pub fn is_zero(&self) -> bool {
+        self.lo == u256::MIN && self.hi == u256::MIN
+    }
+
+    /// Whether the Quire holds a negative sum
+    pub fn is_negative(&self) -> bool {
+        i256::from(self.hi).is_negative()
+    }
+
+    /// Add a term, sign-extended from 256 bits when `neg` is set
+    pub(crate) fn add_term(&mut self, term: u256, neg: bool) {
+        let (lo, carry) = self.lo.overflowing_add(term);
+        let ext = if neg { u256::MAX } else { u256::MIN };
+        self.lo = lo;
+        self.hi = self.hi.wrapping_add(ext).wrapping_add(u256::from(carry as u128));
+    }
+
+    /// Subtract a term, sign-extended from 256 bits when `neg` is set
+    pub(crate) fn sub_term(&mut self, term: u256, neg: bool) {
+        let (lo, borrow) = self.lo.overflowing_sub(term);
+        let ext = if neg { u256::MAX } else { u256::MIN };
+        self.lo = lo;
+        self.hi = self.hi.wrapping_sub(ext).wrapping_sub(u256::from(borrow as u128));
+    }
+
+    /// Round the sum to a magnitude with `shift` fewer fraction bits
+    ///
+    /// Returns the sign, the magnitude and whether the value saturated to `max_pos` or `max_neg`,
+    /// raising the matching status flags
+    pub(crate) fn to_bits(&self, shift: u32, max_pos: u128, max_neg: u128, mode: RoundingMode) -> (bool, u128, bool) {
+        let neg = self.is_negative();
+        let max = if neg { max_neg } else { max_pos };
+        // Two's complement negation across both halves
+        let (lo, hi) = if neg {
+            let (lo, carry) = (!self.lo).overflowing_add(u256::from(1));
+            (lo, (!self.hi).wrapping_add(u256::from(carry as u128)))
+        } else {
+            (self.lo, self.hi)
+        };
+        let wide = shift as u128;
+        let (mag_lo, mag_hi) = if shift == 0 {
+            (lo, hi)
+        } else {
+            ((lo >> wide) | (hi << (256 - wide)), hi >> wide)
+        };
+        if mag_hi != u256::MIN || mag_lo > max {
+            raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+            return (neg, max, true);
+        }
+        let mag = u128::from(mag_lo);
+        let (half, inexact) = if shift == 0 {
+            (Ordering::Less, false)
+        } else {
+            let rem = lo & ((u256::from(1) << wide) - 1);
+            (rem.cmp(&(u256::from(1) << (wide - 1))), rem != u256::MIN)
+        };
+        let up = mode.round_up(neg, mag & 1 == 1, half, inexact);
+        match mag.checked_add(up as u128) {
+            Some(mag) if mag <= max => {
+                if inexact { raise_rounded(mag == 0); }
+                (neg, mag, false)
+            }
+            _ => {
+                raise(StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+                (neg, max, true)
+            }
+        }
+    }
+}
@@ -0,0 +1,553 @@
+#![allow(dead_code)]
+
+use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
+use std::cmp::{Eq, PartialEq, Ordering};
+use std::fmt::{self, Display, Formatter};
+use super::quire256::u256;
+use crate::fixed::signed::Fixed;
+
+#[derive(Copy, Clone, Debug)]
+#[expect(non_camel_case_types)]
+/// Signed companion to the u256 quire.
+/// A two's complement integer that works up to 256 bits.
+///
+/// Used by signed 128-bit Certums to multiply without losing precision
+pub struct i256 {
+    pub bits: [u128; 2]
+}
+
+impl Ord for i256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.bits[0] as i128).cmp(&(other.bits[0] as i128))
+            .then(self.bits[1].cmp(&other.bits[1]))
+    }
+}
+
+impl Eq for i256 { }
+
+impl PartialOrd for i256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for i256 {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl PartialOrd<i128> for i256 {
+    fn partial_cmp(&self, other: &i128) -> Option<Ordering> {
+        Some(self.cmp(&i256::from(*other)))
+    }
+}
+
+impl PartialEq<i128> for i256 {
+    fn eq(&self, other: &i128) -> bool {
+        self.bits == i256::from(*other).bits
+    }
+}
+
+impl Add for i256 {
+    type Output = Self;
+    /// Add two i256s together. Behaves like saturating_add
+    fn add(self, rhs: Self) -> Self {
+        self.saturating_add(rhs)
+    }
+}
+
+impl AddAssign for i256 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub for i256 {
+    type Output = Self;
+    /// Subtract two i256s. Behaves like saturating_sub
+    fn sub(self, rhs: Self) -> Self {
+        self.saturating_sub(rhs)
+    }
+}
+
+impl SubAssign for i256 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl Mul for i256 {
+    type Output = Self;
+    /// Multiply two i256s. Behaves like saturating_mul
+    fn mul(self, rhs: Self) -> Self {
+        self.saturating_mul(rhs)
+    }
+}
+
+impl MulAssign for i256 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl Div for i256 {
+    type Output = Self;
+    /// Divide two i256s, rounding toward zero. Behaves like saturating_div, and panics when dividing by zero
+    fn div(self, rhs: Self) -> Self {
+        self.saturating_div(rhs)
+    }
+}
+
+impl DivAssign for i256 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl Rem for i256 {
+    type Output = Self;
+    /// Remainder of dividing two i256s, with the sign of the dividend. Panics when dividing by zero
+    fn rem(self, rhs: Self) -> Self {
+        self.wrapping_rem(rhs)
+    }
+}
+
+impl RemAssign for i256 {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs
+    }
+}
+
+impl Neg for i256 {
+    type Output = Self;
+    /// Negate an i256. Behaves like saturating_neg, so MIN becomes MAX
+    fn neg(self) -> Self {
+        self.saturating_neg()
+    }
+}
+
+impl Not for i256 {
+    type Output = Self;
+    fn not(self) -> Self {
+        Self { bits: [!self.bits[0], !self.bits[1]] }
+    }
+}
+
+impl Shl<u128> for i256 {
+    type Output = Self;
+    fn shl(self, rhs: u128) -> Self {
+        Self::from(u256::from(self) << rhs)
+    }
+}
+
+impl ShlAssign<u128> for i256 {
+    fn shl_assign(&mut self, rhs: u128) {
+        *self = *self << rhs;
+    }
+}
+
+impl Shr<u128> for i256 {
+    type Output = Self;
+    /// Arithmetic shift right, filling with copies of the sign bit
+    fn shr(self, rhs: u128) -> Self {
+        let shifted = Self::from(u256::from(self) >> rhs);
+        if self.is_negative() && rhs != 0 {
+            // Fill the vacated high bits with ones
+            shifted | Self::from(u256::MAX << (256 - rhs))
+        } else {
+            shifted
+        }
+    }
+}
+
+impl ShrAssign<u128> for i256 {
+    fn shr_assign(&mut self, rhs: u128) {
+        *self = *self >> rhs;
+    }
+}
+
+impl BitAnd for i256 {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        Self { bits: [self.bits[0] & rhs.bits[0], self.bits[1] & rhs.bits[1]] }
+    }
+}
+
+impl BitAndAssign for i256 {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+impl BitOr for i256 {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        Self { bits: [self.bits[0] | rhs.bits[0], self.bits[1] | rhs.bits[1]] }
+    }
+}
+
+impl BitOrAssign for i256 {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl BitXor for i256 {
+    type Output = Self;
+    fn bitxor(self, rhs: Self) -> Self {
+        Self { bits: [self.bits[0] ^ rhs.bits[0], self.bits[1] ^ rhs.bits[1]] }
+    }
+}
+
+impl BitXorAssign for i256 {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = *self ^ rhs;
+    }
+}
+
+impl Display for i256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.is_negative(), "", &self.unsigned_abs().to_string())
+    }
+}
+
+impl From<i128> for i256 {
+    /// Convert an i128 to an i256.
+    ///
+    /// Equivalent to using `as` to cast from a smaller int to a bigger int
+    fn from(value: i128) -> Self {
+        Self { bits: [(value >> 127) as u128, value as u128] }
+    }
+}
+
+impl From<i256> for i128 {
+    /// Convert an i256 to an i128.
+    ///
+    /// Equivalent to using `as` to cast from a bigger int to a smaller int
+    fn from(value: i256) -> Self {
+        value.bits[1] as i128
+    }
+}
+
+impl From<u256> for i256 {
+    /// Reinterpret the bits of a u256 as two's complement.
+    ///
+    /// Equivalent to using `as` to cast between ints of the same size
+    fn from(value: u256) -> Self {
+        Self { bits: value.bits }
+    }
+}
+
+impl From<i256> for u256 {
+    /// Reinterpret the two's complement bits of an i256 as unsigned.
+    ///
+    /// Equivalent to using `as` to cast between ints of the same size
+    fn from(value: i256) -> Self {
+        u256::from_arr(value.bits)
+    }
+}
+
+impl<const F: u32> From<Fixed<u128, F>> for i256 {
+    /// Sign-extend the raw bits of a signed 128-bit type
+    fn from(value: Fixed<u128, F>) -> Self {
+        Self::from(value.bits as i128)
+    }
+}
+
+impl<const F: u32> From<i256> for Fixed<u128, F> {
+    /// Take an i256 as the raw bits of a signed 128-bit type, with the same fraction bits.
+    ///
+    /// Values outside the 128-bit range saturate to MIN/MAX
+    fn from(value: i256) -> Self {
+        if value > i256::from(i128::MAX) { return Self::MAX; }
+        if value < i256::from(i128::MIN) { return Self::MIN; }
+        Self { bits: value.bits[1] }
+    }
+}
+
+impl i256 {
+    /// Maximum i256 Integer
+    ///
+    /// Decimal Equivalent: 57896044618658097711785492504343953926634992332820282019728792003956564819967
+    pub const MAX: i256 = Self { bits: [i128::MAX as u128, u128::MAX] };
+    /// Minimum i256 Integer
+    ///
+    /// Decimal Equivalent: -57896044618658097711785492504343953926634992332820282019728792003956564819968
+    pub const MIN: i256 = Self { bits: [1 << 127, 0] };
+    /// Size of the integer in bits
+    pub const BITS: u32 = 256;
+
+    /// Create a new i256 from an array of u128s, most significant half first
+    pub fn from_arr(arr: [u128; 2]) -> Self {
+        Self { bits: arr }
+    }
+
+    /// Multiply two i128's and return the exact i256 product
+    ///
+    /// Used for higher-order operations that need extra precision, like signed 128-bit certum multiplication.
+    pub fn from_mul(lhs: i128, rhs: i128) -> Self {
+        let mag = u256::from_mul(lhs.unsigned_abs(), rhs.unsigned_abs());
+        // At most 2^254, so the magnitude always fits
+        if (lhs < 0) != (rhs < 0) {
+            Self::from(mag.wrapping_neg())
+        } else {
+            Self::from(mag)
+        }
+    }
+
+    /// Whether the value is below zero
+    pub const fn is_negative(self) -> bool {
+        self.bits[0] >> 127 == 1
+    }
+
+    /// Whether the value is above zero
+    pub const fn is_positive(self) -> bool {
+        !self.is_negative() && (self.bits[0] | self.bits[1]) != 0
+    }
+
+    /// -1 for negative values, 0 for zero and 1 for positive values
+    pub fn signum(self) -> Self {
+        if self.is_negative() {
+            Self::from(-1)
+        } else {
+            Self::from(self.is_positive() as i128)
+        }
+    }
+
+    /// Absolute value as a u256, which cannot overflow
+    pub fn unsigned_abs(self) -> u256 {
+        if self.is_negative() {
+            u256::from(self).wrapping_neg()
+        } else {
+            u256::from(self)
+        }
+    }
+
+    /// Absolute value. Behaves like saturating_abs, so MIN becomes MAX
+    pub fn abs(self) -> Self {
+        self.saturating_abs()
+    }
+
+    /// Number of ones in the binary representation
+    pub const fn count_ones(self) -> u32 {
+        self.bits[0].count_ones() + self.bits[1].count_ones()
+    }
+
+    /// Number of zeros in the binary representation
+    pub const fn count_zeros(self) -> u32 {
+        self.bits[0].count_zeros() + self.bits[1].count_zeros()
+    }
+
+    /// Number of leading zeros in the binary representation
+    pub const fn leading_zeros(self) -> u32 {
+        u256 { bits: self.bits }.leading_zeros()
+    }
+
+    /// Number of trailing zeros in the binary representation
+    pub const fn trailing_zeros(self) -> u32 {
+        u256 { bits: self.bits }.trailing_zeros()
+    }
+
+    /// Add, returning the wrapped sum and whether it overflowed
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let res = Self::from(u256::from(self).wrapping_add(u256::from(rhs)));
+        let overflow = (self.is_negative() == rhs.is_negative()) && (res.is_negative() != self.is_negative());
+        (res, overflow)
+    }
+
+    /// Subtract, returning the wrapped difference and whether it overflowed
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let res = Self::from(u256::from(self).wrapping_sub(u256::from(rhs)));
+        let overflow = (self.is_negative() != rhs.is_negative()) && (res.is_negative() != self.is_negative());
+        (res, overflow)
+    }
+
+    /// Multiply, returning the wrapped product and whether it overflowed
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let res = Self::from(u256::from(self).wrapping_mul(u256::from(rhs)));
+        let (mag, high) = self.unsigned_abs().widening_mul(rhs.unsigned_abs());
+        let negative = self.is_negative() != rhs.is_negative();
+        // A negative product may reach 2^255, a positive one only 2^255 - 1
+        let limit = if negative { u256::from(Self::MIN) } else { u256::from(Self::MAX) };
+        (res, high != u256::MIN || mag > limit)
+    }
+
+    /// Divide, rounding toward zero, returning whether it overflowed. Panics when dividing by zero
+    ///
+    /// Only MIN / -1 overflows, and wraps to MIN
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        if self == Self::MIN && rhs == -1 {
+            return (Self::MIN, true);
+        }
+        let quo = Self::from(self.unsigned_abs() / rhs.unsigned_abs());
+        if self.is_negative() != rhs.is_negative() {
+            (quo.wrapping_neg(), false)
+        } else {
+            (quo, false)
+        }
+    }
+
+    /// Remainder with the sign of the dividend, returning whether it overflowed. Panics when dividing by zero
+    ///
+    /// Only MIN % -1 overflows, and returns zero
+    pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        if self == Self::MIN && rhs == -1 {
+            return (Self::from(0), true);
+        }
+        let rem = Self::from(self.unsigned_abs() % rhs.unsigned_abs());
+        if self.is_negative() {
+            (rem.wrapping_neg(), false)
+        } else {
+            (rem, false)
+        }
+    }
+
+    /// Negate, returning whether it overflowed. Only MIN overflows, and stays MIN
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        (Self::from(u256::from(self).wrapping_neg()), self == Self::MIN)
+    }
+
+    /// Absolute value, returning whether it overflowed. Only MIN overflows, and stays MIN
+    pub fn overflowing_abs(self) -> (Self, bool) {
+        if self.is_negative() { self.overflowing_neg() } else { (self, false) }
+    }
+
+    /// Add, returning None on overflow
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (res, false) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Subtract, returning None on overflow
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (res, false) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Multiply, returning None on overflow
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (res, false) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Divide, returning None when dividing by zero or on overflow
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs == 0 { return None; }
+        match self.overflowing_div(rhs) {
+            (res, false) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Remainder, returning None when dividing by zero or on overflow
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs == 0 { return None; }
+        match self.overflowing_rem(rhs) {
+            (res, false) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Negate, returning None for MIN
+    pub fn checked_neg(self) -> Option<Self> {
+        match self.overflowing_neg() {
+            (res, false) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Absolute value, returning None for MIN
+    pub fn checked_abs(self) -> Option<Self> {
+        match self.overflowing_abs() {
+            (res, false) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Add, wrapping around at the boundary of the type
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Subtract, wrapping around at the boundary of the type
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Multiply, wrapping around at the boundary of the type
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// Divide, wrapping MIN / -1 to MIN. Panics when dividing by zero
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self.overflowing_div(rhs).0
+    }
+
+    /// Remainder, returning zero for MIN % -1. Panics when dividing by zero
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self.overflowing_rem(rhs).0
+    }
+
+    /// Negate, wrapping MIN to MIN
+    pub fn wrapping_neg(self) -> Self {
+        self.overflowing_neg().0
+    }
+
+    /// Absolute value, wrapping MIN to MIN
+    pub fn wrapping_abs(self) -> Self {
+        self.overflowing_abs().0
+    }
+
+    /// Add, saturating at MIN and MAX
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        match self.overflowing_add(rhs) {
+            (res, false) => res,
+            _ if rhs.is_negative() => Self::MIN,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Subtract, saturating at MIN and MAX
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        match self.overflowing_sub(rhs) {
+            (res, false) => res,
+            _ if rhs.is_negative() => Self::MAX,
+            _ => Self::MIN,
+        }
+    }
+
+    /// Multiply, saturating at MIN and MAX
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        match self.overflowing_mul(rhs) {
+            (res, false) => res,
+            _ if self.is_negative() != rhs.is_negative() => Self::MIN,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Divide, saturating MIN / -1 to MAX. Panics when dividing by zero
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        match self.overflowing_div(rhs) {
+            (res, false) => res,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Negate, saturating MIN to MAX
+    pub fn saturating_neg(self) -> Self {
+        self.checked_neg().unwrap_or(Self::MAX)
+    }
+
+    /// Absolute value, saturating MIN to MAX
+    pub fn saturating_abs(self) -> Self {
+        self.checked_abs().unwrap_or(Self::MAX)
+    }
+}
@@ -1 +1,3 @@
-pub mod quire256;
\ No newline at end of file
+pub mod quire256;
+pub mod i256;
+pub mod accumulator;
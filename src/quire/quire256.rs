@@ -1,7 +1,10 @@
 #![allow(dead_code)]
 
-use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Mul, MulAssign, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign};
 use std::cmp::{Eq, PartialEq, Ordering};
+use std::fmt::{self, Binary, Display, Formatter, LowerHex, Octal, UpperHex};
+use std::str::FromStr;
+use crate::fixed::decimal::{ParseCertumError, ParseErrorKind};
 
 #[derive(Copy, Clone, Debug)]
 #[expect(non_camel_case_types)]
@@ -31,10 +34,6 @@ impl PartialEq for u256 {
     fn eq(&self, other: &Self) -> bool {
         self.bits == other.bits
     }
-
-    fn ne(&self, other: &Self) -> bool {
-        self.bits != other.bits
-    }
 }
 
 impl PartialOrd<u128> for u256 {
@@ -47,24 +46,13 @@ impl PartialEq<u128> for u256 {
     fn eq(&self, other: &u128) -> bool {
         self.bits == u256::from(*other).bits
     }
-
-    fn ne(&self, other: &u128) -> bool {
-        self.bits != u256::from(*other).bits
-    }
 }
 
 impl Add for u256 {
     type Output = Self;
     /// Add two u256s together. Behaves like saturating_add
     fn add(self, rhs: Self) -> Self {
-        let (ov_right, c1) = u128::overflowing_add(self.bits[1], rhs.bits[1]);
-        let (ov_left, c2) = u128::overflowing_add(self.bits[0], rhs.bits[0]);
-        let (ov_carry, c3) = u128::overflowing_add(ov_left, c1 as u128);
-        if c2 || c3 {
-            Self::MAX
-        } else {
-            Self { bits: [ov_carry, ov_right] }
-        }
+        self.saturating_add(rhs)
     }
 }
 
@@ -92,15 +80,7 @@ impl Sub for u256 {
     type Output = Self;
     /// Subtract two u256s. Behaves like saturating_sub
     fn sub(self, rhs: Self) -> Self {
-        if rhs >= self { return Self::MIN }
-        let (ov_right, c1) = u128::overflowing_sub(self.bits[1], rhs.bits[1]);
-        let (ov_left, c2) = u128::overflowing_sub(self.bits[0], rhs.bits[0]);
-        let (ov_carry, c3) = u128::overflowing_sub(ov_left, c1 as u128);
-        if c2 || c3 {
-            Self::MIN
-        } else {
-            Self { bits: [ov_carry, ov_right] }
-        }
+        self.saturating_sub(rhs)
     }
 }
 
@@ -124,30 +104,102 @@ impl SubAssign<u128> for u256 {
     }
 }
 
-// impl Mul<u128> for u256 {
-//     type Output = Self;
-//     fn mul(self, rhs: u128) -> Self {
-//         let (ov_right, c1) = u128::overflowing_mul(self.bits[1], rhs);
-//         let (ov_left, c2) = u128::overflowing_mul(self.bits[0], rhs);
-//         let (ov_carry, c3) = u128::overflowing_add(ov_left, c1 as u128);
-//         if c2 || c3 {
-//             Self::MAX
-//         } else {
-//             Self { bits: [ov_carry, ov_right] }
-//         }
-//     }
-// }
+impl Mul for u256 {
+    type Output = Self;
+    /// Multiply two u256s. Behaves like saturating_mul
+    fn mul(self, rhs: Self) -> Self {
+        self.saturating_mul(rhs)
+    }
+}
+
+impl MulAssign for u256 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl Mul<u128> for u256 {
+    type Output = Self;
+    /// Multiply a u256 by a u128. Behaves like saturating_mul
+    fn mul(self, rhs: u128) -> Self {
+        self * Self::from(rhs)
+    }
+}
+
+impl MulAssign<u128> for u256 {
+    fn mul_assign(&mut self, rhs: u128) {
+        *self = *self * rhs
+    }
+}
+
+impl Div for u256 {
+    type Output = Self;
+    /// Divide two u256s, rounding toward zero. Panics when dividing by zero
+    fn div(self, rhs: Self) -> Self {
+        self.div_rem(rhs).0
+    }
+}
+
+impl DivAssign for u256 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl Div<u128> for u256 {
+    type Output = Self;
+    /// Divide a u256 by a u128, rounding toward zero. Panics when dividing by zero
+    fn div(self, rhs: u128) -> Self {
+        self / Self::from(rhs)
+    }
+}
+
+impl DivAssign<u128> for u256 {
+    fn div_assign(&mut self, rhs: u128) {
+        *self = *self / rhs
+    }
+}
+
+impl Rem for u256 {
+    type Output = Self;
+    /// Remainder of dividing two u256s. Panics when dividing by zero
+    fn rem(self, rhs: Self) -> Self {
+        self.div_rem(rhs).1
+    }
+}
+
+impl RemAssign for u256 {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs
+    }
+}
 
-// impl MulAssign<u128> for u256 {
-//     fn mul_assign(&mut self, rhs: u128) {
-//         *self = *self * rhs
-//     }
-// }
+impl Rem<u128> for u256 {
+    type Output = Self;
+    /// Remainder of dividing a u256 by a u128. Panics when dividing by zero
+    fn rem(self, rhs: u128) -> Self {
+        self % Self::from(rhs)
+    }
+}
+
+impl RemAssign<u128> for u256 {
+    fn rem_assign(&mut self, rhs: u128) {
+        *self = *self % rhs
+    }
+}
+
+impl Not for u256 {
+    type Output = Self;
+    fn not(self) -> Self {
+        Self { bits: [!self.bits[0], !self.bits[1]] }
+    }
+}
 
 impl Shl<u128> for u256 {
     type Output = Self;
     fn shl(self, rhs: u128) -> Self {
         assert!(rhs <= 255u128);
+        if rhs == 0 { return self; }
         let (res_left, res_right);
         if rhs >= 128 {
             let adj = rhs - 128;
@@ -173,6 +225,7 @@ impl Shr<u128> for u256 {
     type Output = Self;
     fn shr(self, rhs: u128) -> Self {
         assert!(rhs <= 255u128);
+        if rhs == 0 { return self; }
         let (res_left, res_right);
         if rhs >= 128 {
             let adj = rhs - 128;
@@ -253,15 +306,55 @@ impl From<u128> for u256 {
     }
 }
 
+impl Display for u256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "", &self.to_string_radix(10))
+    }
+}
+
+impl LowerHex for u256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_string_radix(16))
+    }
+}
+
+impl UpperHex for u256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0x", &self.to_string_radix(16).to_uppercase())
+    }
+}
+
+impl Binary for u256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0b", &self.to_string_radix(2))
+    }
+}
+
+impl Octal for u256 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "0o", &self.to_string_radix(8))
+    }
+}
+
+impl FromStr for u256 {
+    type Err = ParseCertumError;
+    /// Parse a decimal integer
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(src, 10)
+    }
+}
+
 impl u256 {
     /// Maximum u256 Integer
     /// 
-    /// Decimal Equivalent: 115792089237316195423570985008687907852589419931798687112530834793049593217025
+    /// Decimal Equivalent: 115792089237316195423570985008687907853269984665640564039457584007913129639935
     pub const MAX: u256 = Self { bits: [u128::MAX, u128::MAX] };
     /// Minimum u256 Interger
     /// 
     /// Decimal Equivalent: 0
     pub const MIN: u256 = Self { bits: [0, 0] };
+    /// Size of the integer in bits
+    pub const BITS: u32 = 256;
 
     /// Create a new u256 from an array of u128s
     pub fn from_arr(arr: [u128; 2]) -> Self {
@@ -275,4 +368,368 @@ impl u256 {
         let (rh, lh) = lhs.carrying_mul(rhs, 0);
         Self { bits: [lh, rh] }
     }
-}
\ No newline at end of file
+
+    /// Divide two u256's and return both the quotient and the remainder
+    /// 
+    /// Simple binary long division, one bit per step. Panics when dividing by zero, like the primitive integers.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        assert!(rhs != Self::MIN, "attempt to divide by zero");
+        if (self.bits[0] | rhs.bits[0]) == 0 {
+            return (Self::from(self.bits[1] / rhs.bits[1]), Self::from(self.bits[1] % rhs.bits[1]));
+        }
+        if rhs > self {
+            return (Self::MIN, self);
+        }
+        let mut quo = Self::MIN;
+        let mut rem = Self::MIN;
+        // Bits above the highest set bit of self would only shift in zeroes
+        for i in (0..(Self::BITS - self.leading_zeros()) as u128).rev() {
+            // Bit shifted out of the remainder. If set, the remainder is already larger than rhs
+            let carry = rem.bits[0] >> 127 == 1;
+            rem = (rem << 1) | ((self >> i) & Self::from(1));
+            if carry || rem >= rhs {
+                rem = rem.wrapping_sub(rhs);
+                quo |= Self::from(1) << i;
+            }
+        }
+        (quo, rem)
+    }
+
+    /// Multiply two u256's and return the full 512-bit product as (low, high) halves
+    pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
+        // Little-endian limbs
+        let (lhs, rhs) = ([self.bits[1], self.bits[0]], [rhs.bits[1], rhs.bits[0]]);
+        let mut res = [0u128; 4];
+        for i in 0..2 {
+            let mut carry = 0u128;
+            for j in 0..2 {
+                let (lo, hi) = lhs[i].carrying_mul(rhs[j], carry);
+                let (sum, c) = res[i + j].overflowing_add(lo);
+                res[i + j] = sum;
+                // hi is at most u128::MAX - 1, so adding the carry cannot overflow
+                carry = hi + c as u128;
+            }
+            res[i + 2] = carry;
+        }
+        (Self { bits: [res[1], res[0]] }, Self { bits: [res[3], res[2]] })
+    }
+
+    /// Number of ones in the binary representation
+    pub const fn count_ones(self) -> u32 {
+        self.bits[0].count_ones() + self.bits[1].count_ones()
+    }
+
+    /// Number of zeros in the binary representation
+    pub const fn count_zeros(self) -> u32 {
+        self.bits[0].count_zeros() + self.bits[1].count_zeros()
+    }
+
+    /// Number of leading zeros in the binary representation
+    pub const fn leading_zeros(self) -> u32 {
+        if self.bits[0] == 0 {
+            128 + self.bits[1].leading_zeros()
+        } else {
+            self.bits[0].leading_zeros()
+        }
+    }
+
+    /// Number of trailing zeros in the binary representation
+    pub const fn trailing_zeros(self) -> u32 {
+        if self.bits[1] == 0 {
+            128 + self.bits[0].trailing_zeros()
+        } else {
+            self.bits[1].trailing_zeros()
+        }
+    }
+
+    /// Number of leading ones in the binary representation
+    pub const fn leading_ones(self) -> u32 {
+        Self { bits: [!self.bits[0], !self.bits[1]] }.leading_zeros()
+    }
+
+    /// Number of trailing ones in the binary representation
+    pub const fn trailing_ones(self) -> u32 {
+        Self { bits: [!self.bits[0], !self.bits[1]] }.trailing_zeros()
+    }
+
+    /// Whether exactly one bit is set
+    pub const fn is_power_of_two(self) -> bool {
+        self.count_ones() == 1
+    }
+
+    /// Base 2 logarithm, rounded down. Panics when self is zero
+    pub const fn ilog2(self) -> u32 {
+        assert!(self.bits[0] != 0 || self.bits[1] != 0, "argument of integer logarithm must be positive");
+        Self::BITS - 1 - self.leading_zeros()
+    }
+
+    /// Square root, rounded down
+    pub fn isqrt(self) -> Self {
+        if self.bits[0] == 0 { return Self::from(self.bits[1].isqrt()); }
+        // Newton's method from a power of two above the root only ever decreases
+        let mut x = Self::from(1) << (self.ilog2() / 2 + 1) as u128;
+        loop {
+            let y = (x + self / x) >> 1;
+            if y >= x { return x; }
+            x = y;
+        }
+    }
+
+    /// Absolute difference between two u256's
+    pub fn abs_diff(self, rhs: Self) -> Self {
+        if self > rhs { self.wrapping_sub(rhs) } else { rhs.wrapping_sub(self) }
+    }
+
+    /// Add, returning the wrapped sum and whether it overflowed
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (low, c1) = self.bits[1].overflowing_add(rhs.bits[1]);
+        let (high, c2) = self.bits[0].overflowing_add(rhs.bits[0]);
+        let (high, c3) = high.overflowing_add(c1 as u128);
+        (Self { bits: [high, low] }, c2 | c3)
+    }
+
+    /// Subtract, returning the wrapped difference and whether it overflowed
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (low, b1) = self.bits[1].overflowing_sub(rhs.bits[1]);
+        let (high, b2) = self.bits[0].overflowing_sub(rhs.bits[0]);
+        let (high, b3) = high.overflowing_sub(b1 as u128);
+        (Self { bits: [high, low] }, b2 | b3)
+    }
+
+    /// Multiply, returning the wrapped product and whether it overflowed
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (low, high) = self.widening_mul(rhs);
+        (low, high != Self::MIN)
+    }
+
+    /// Divide, returning the quotient and false, as unsigned division cannot overflow. Panics when dividing by zero
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (self / rhs, false)
+    }
+
+    /// Remainder, returned with false, as unsigned division cannot overflow. Panics when dividing by zero
+    pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        (self % rhs, false)
+    }
+
+    /// Negate, returning the two's complement and whether self was nonzero
+    pub const fn overflowing_neg(self) -> (Self, bool) {
+        let neg = Self::MIN.overflowing_sub(self).0;
+        (neg, self.bits[0] != 0 || self.bits[1] != 0)
+    }
+
+    /// Shift left by rhs modulo 256, returning whether rhs was out of range
+    pub fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+        (self << (rhs % Self::BITS) as u128, rhs >= Self::BITS)
+    }
+
+    /// Shift right by rhs modulo 256, returning whether rhs was out of range
+    pub fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+        (self >> (rhs % Self::BITS) as u128, rhs >= Self::BITS)
+    }
+
+    /// Raise to a power, returning the wrapped result and whether it overflowed
+    pub fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
+        let (mut base, mut acc, mut overflow) = (self, Self::from(1), false);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                let (res, o) = acc.overflowing_mul(base);
+                acc = res;
+                overflow |= o;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                let (res, o) = base.overflowing_mul(base);
+                base = res;
+                overflow |= o;
+            }
+        }
+        (acc, overflow)
+    }
+
+    /// Add, returning None on overflow
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (res, false) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Subtract, returning None on overflow
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (res, false) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Multiply, returning None on overflow
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (res, false) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Divide, returning None when dividing by zero
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs == Self::MIN { None } else { Some(self / rhs) }
+    }
+
+    /// Remainder, returning None when dividing by zero
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs == Self::MIN { None } else { Some(self % rhs) }
+    }
+
+    /// Negate, returning None unless self is zero
+    pub fn checked_neg(self) -> Option<Self> {
+        if self == Self::MIN { Some(self) } else { None }
+    }
+
+    /// Shift left, returning None when rhs is 256 or more
+    pub fn checked_shl(self, rhs: u32) -> Option<Self> {
+        if rhs < Self::BITS { Some(self << rhs as u128) } else { None }
+    }
+
+    /// Shift right, returning None when rhs is 256 or more
+    pub fn checked_shr(self, rhs: u32) -> Option<Self> {
+        if rhs < Self::BITS { Some(self >> rhs as u128) } else { None }
+    }
+
+    /// Raise to a power, returning None on overflow
+    pub fn checked_pow(self, exp: u32) -> Option<Self> {
+        match self.overflowing_pow(exp) {
+            (res, false) => Some(res),
+            _ => None,
+        }
+    }
+
+    /// Add, wrapping around at the boundary of the type
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Subtract, wrapping around at the boundary of the type
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+
+    /// Multiply, wrapping around at the boundary of the type
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.widening_mul(rhs).0
+    }
+
+    /// Divide. Unsigned division cannot wrap, so this is the same as `/`
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    /// Remainder. Unsigned division cannot wrap, so this is the same as `%`
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self % rhs
+    }
+
+    /// Two's complement negation
+    pub const fn wrapping_neg(self) -> Self {
+        self.overflowing_neg().0
+    }
+
+    /// Shift left by rhs modulo 256
+    pub fn wrapping_shl(self, rhs: u32) -> Self {
+        self.overflowing_shl(rhs).0
+    }
+
+    /// Shift right by rhs modulo 256
+    pub fn wrapping_shr(self, rhs: u32) -> Self {
+        self.overflowing_shr(rhs).0
+    }
+
+    /// Raise to a power, wrapping around at the boundary of the type
+    pub fn wrapping_pow(self, exp: u32) -> Self {
+        self.overflowing_pow(exp).0
+    }
+
+    /// Add, saturating at MAX
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match self.overflowing_add(rhs) {
+            (res, false) => res,
+            _ => Self::MAX,
+        }
+    }
+
+    /// Subtract, saturating at MIN
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match self.overflowing_sub(rhs) {
+            (res, false) => res,
+            _ => Self::MIN,
+        }
+    }
+
+    /// Multiply, saturating at MAX
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or(Self::MAX)
+    }
+
+    /// Divide. Unsigned division cannot saturate, so this is the same as `/`
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    /// Raise to a power, saturating at MAX
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        self.checked_pow(exp).unwrap_or(Self::MAX)
+    }
+
+    /// Raise to a power. Behaves like saturating_pow, matching the operators
+    pub fn pow(self, exp: u32) -> Self {
+        self.saturating_pow(exp)
+    }
+
+    /// Parse an integer in the given radix, with an optional leading `+`
+    /// 
+    /// Panics if the radix is not in 2..=36, like the primitive integers.
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseCertumError> {
+        assert!((2..=36).contains(&radix), "from_str_radix: radix must lie in the range `[2, 36]`");
+        let digits = src.strip_prefix('+').unwrap_or(src);
+        if digits.is_empty() {
+            return Err(ParseCertumError::new(ParseErrorKind::Empty));
+        }
+        let mut res = Self::MIN;
+        for c in digits.chars() {
+            let digit = c.to_digit(radix).ok_or(ParseCertumError::new(ParseErrorKind::InvalidDigit))?;
+            res = res.checked_mul(Self::from(radix as u128))
+                .and_then(|v| v.checked_add(Self::from(digit as u128)))
+                .ok_or(ParseCertumError::new(ParseErrorKind::PosOverflow))?;
+        }
+        Ok(res)
+    }
+
+    /// Write the integer in the given radix, using lowercase letters past 9
+    /// 
+    /// Panics if the radix is not in 2..=36, like the primitive integers.
+    pub fn to_string_radix(self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "to_string_radix: radix must lie in the range `[2, 36]`");
+        let mut digits: Vec<char> = Vec::new();
+        let mut cur = self;
+        loop {
+            let (quo, rem) = cur.div_rem_small(radix as u64);
+            digits.push(char::from_digit(rem as u32, radix).unwrap_or('0'));
+            cur = quo;
+            if cur == Self::MIN { break }
+        }
+        digits.iter().rev().collect()
+    }
+
+    /// Divide by a small divisor, one 64-bit limb at a time
+    fn div_rem_small(self, rhs: u64) -> (Self, u64) {
+        let limbs = [self.bits[0] >> 64, self.bits[0] & u64::MAX as u128, self.bits[1] >> 64, self.bits[1] & u64::MAX as u128];
+        let mut quo = [0u128; 4];
+        let mut rem: u128 = 0;
+        for (q, limb) in quo.iter_mut().zip(limbs) {
+            let cur = (rem << 64) | limb;
+            *q = cur / rhs as u128;
+            rem = cur % rhs as u128;
+        }
+        (Self { bits: [(quo[0] << 64) | quo[1], (quo[2] << 64) | quo[3]] }, rem as u64)
+    }
+}
@@ -0,0 +1,42 @@
+use std::cmp::Ordering;
+
+/// How a result that falls between two representable values is resolved
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest value, ties to the one with an even last bit
+    #[default]
+    NearestEven,
+    /// Round to the nearest value, ties away from zero
+    NearestAway,
+    /// Truncate toward zero
+    TowardZero,
+    /// Round toward positive infinity
+    TowardPositive,
+    /// Round toward negative infinity
+    TowardNegative,
+}
+
+impl RoundingMode {
+    /// Decide whether a magnitude truncated toward zero should be incremented
+    ///
+    /// `neg` is the sign of the exact value, `odd` whether the truncated magnitude is odd,
+    /// `half` how the discarded remainder compares to half of the last bit,
+    /// and `inexact` whether any remainder was discarded at all
+    pub const fn round_up(self, neg: bool, odd: bool, half: Ordering, inexact: bool) -> bool {
+        match self {
+            Self::NearestEven => matches!(half, Ordering::Greater) || (matches!(half, Ordering::Equal) && odd),
+            Self::NearestAway => !matches!(half, Ordering::Less),
+            Self::TowardZero => false,
+            Self::TowardPositive => inexact && !neg,
+            Self::TowardNegative => inexact && neg,
+        }
+    }
+}
+
+/// Conversion that rounds with a selectable `RoundingMode`
+///
+/// The plain `From` conversions round to nearest (ties to even), this lets the caller pick the mode
+pub trait FromRound<T>: Sized {
+    /// Convert `value`, rounding any discarded bits with `mode`
+    fn from_round(value: T, mode: RoundingMode) -> Self;
+}
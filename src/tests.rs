@@ -1,7 +1,14 @@
-#![allow(unused_imports)]
+#![allow(unused_imports, unused_parens, non_camel_case_types)]
+#![allow(clippy::bool_assert_comparison, clippy::approx_constant, clippy::excessive_precision, clippy::useless_conversion, clippy::double_parens)]
 
 use {
-    super::{c8, c16, c32, c64, c128, u256, uc8, uc16, uc32, uc64, uc128}, crate::{from_direct, from_left_shift, from_right_shift, utils::QuickLog}, core::{f32, f64}
+    super::{c8, c16, c32, c64, c128, u256, i256, uc8, uc16, uc32, uc64, uc128},
+    super::{q8, q16, q32, q64, q128, uq8, uq16, uq32, uq64, uq128},
+    super::{d8, d16, d32, d64, d128, ud8, ud16, ud32, ud64, ud128},
+    super::{a8, a16, a32, a64, a128, ua8, ua16, ua32, ua64, ua128},
+    super::{Fixed, UFixed, ParseErrorKind, ALN, RoundingMode, FromRound, Quire, CertumError, FromChecked, StatusFlags, with_flags, FixedPoint},
+    std::cmp::Ordering,
+    crate::{from_direct, from_storage, from_storage_signed, utils::QuickLog}, core::{f32, f64}
 };
 
 #[test]
@@ -79,26 +86,37 @@ pub fn conversion_tests() {
     let min_high = c128::MAX;
     let max_low = c8::MIN;
     let max_high = c128::MAX;
+    // Widening keeps the value, narrowing saturates
     assert_eq!(c8::from(min_low),    c8::MIN);
-    assert_eq!(c16::from(min_low),   c16::MIN);
-    assert_eq!(c32::from(min_low),   c32::MIN);
-    assert_eq!(c64::from(min_low),   c64::MIN);
-    assert_eq!(c128::from(min_low),  c128::MIN);
+    assert_eq!(c16::from(min_low),   c16::from(-2.0));
+    assert_eq!(c32::from(min_low),   c32::from(-2.0));
+    assert_eq!(c64::from(min_low),   c64::from(-2.0));
+    assert_eq!(c128::from(min_low),  c128::from(-2.0));
     assert_eq!(c8::from(min_high),   c8::MAX);
     assert_eq!(c16::from(min_high),  c16::MAX);
     assert_eq!(c32::from(min_high),  c32::MAX);
     assert_eq!(c64::from(min_high),  c64::MAX);
     assert_eq!(c128::from(min_high), c128::MAX);
     assert_eq!(c8::from(max_low),    c8::MIN);
-    assert_eq!(c16::from(max_low),   c16::MIN);
-    assert_eq!(c32::from(max_low),   c32::MIN);
-    assert_eq!(c64::from(max_low),   c64::MIN);
-    assert_eq!(c128::from(max_low),  c128::MIN);
+    assert_eq!(c16::from(max_low),   c16::from(-2.0));
+    assert_eq!(c32::from(max_low),   c32::from(-2.0));
+    assert_eq!(c64::from(max_low),   c64::from(-2.0));
+    assert_eq!(c128::from(max_low),  c128::from(-2.0));
     assert_eq!(c8::from(max_high),   c8::MAX);
     assert_eq!(c16::from(max_high),  c16::MAX);
     assert_eq!(c32::from(max_high),  c32::MAX);
     assert_eq!(c64::from(max_high),  c64::MAX);
     assert_eq!(c128::from(max_high), c128::MAX);
+
+    // MIN and MAX are ordinary values, so they survive a round trip through a wider layout
+    assert_eq!(c64::from(c32::MIN), c64::from(-8.0));
+    assert_eq!(c32::from(c64::from(c32::MIN)), c32::MIN);
+    assert_eq!(c32::from(c64::from(c32::MAX)), c32::MAX);
+    assert_eq!(c16::from(c8::MAX), c8::MAX);
+    assert_eq!(d16::from(c8::MAX), d16::from(1.984375));
+    assert_eq!(c32::MAX.rescale::<16>(), Fixed::<u32, 16>::from(8.0));
+    assert_eq!(c32::MIN.rescale::<16>(), Fixed::<u32, 16>::from(-8.0));
+    assert_eq!(uc32::from(uc16::MAX), uc32::from(8.0) - uc32::from(uc16::GRN));
 }
 
 #[test]
@@ -117,13 +135,14 @@ pub fn constants_tests() {
     assert_eq!(f64::from(uc32::MIN), uc32::MINF);
     assert_eq!(f64::from(uc32::MAX), uc32::MAXF);
     assert_eq!(f64::from(c64::MIN),  c64::MINF);
-    assert_eq!(f64::from(c64::MAX),  c64::MAXF);
+    assert!(c64::from(c64::MAXF) < c64::MAX && c64::MAXF < f64::from(c64::MAX));
+    assert_eq!(c64::MAXF, 16.0 - 2f64.powi(-49));
     assert_eq!(f64::from(uc64::MIN), uc64::MINF);
-    assert_eq!(f64::from(uc64::MAX), uc64::MAXF);
-    assert_eq!(f64::from(c128::MIN), c64::MINF);
-    assert_eq!(f64::from(c128::MAX), c64::MAXF);
-    assert_eq!(f64::from(uc128::MIN), uc64::MINF);
-    assert_eq!(f64::from(uc128::MAX), uc64::MAXF);
+    assert!(uc64::from(uc64::MAXF) < uc64::MAX && uc64::MAXF < f64::from(uc64::MAX));
+    assert_eq!(f64::from(c128::MIN), c128::MINF);
+    assert!(c128::from(c128::MAXF) < c128::MAX && c128::MAXF < f64::from(c128::MAX));
+    assert_eq!(f64::from(uc128::MIN), uc128::MINF);
+    assert!(uc128::from(uc128::MAXF) < uc128::MAX && uc128::MAXF < f64::from(uc128::MAX));
     assert_eq!(f64::from(uc8::PI),   3.140625);
     assert_eq!(f64::from(c16::PI),   3.1414794921875);
     assert_eq!(f64::from(uc16::PI),  3.1414794921875);
@@ -306,6 +325,1494 @@ pub fn u256_tests() {
     assert_eq!(u256::MIN - 1, u256::MIN);
 
     // Carrying-Multiply Edge Cases
-    assert_eq!(u256::from_mul(u128::MAX, u128::MAX), u256::MAX);
-    assert_eq!(u256::from_mul(u128::MAX, u128::MAX - 1), ((u256::MAX - 1) << 128) + 1);
-}
\ No newline at end of file
+    assert_eq!(u256::from_mul(u128::MAX, u128::MAX), u256::from_arr([u128::MAX - 1, 1]));
+    assert_eq!(u256::from_mul(u128::MAX, u128::MAX - 1), u256::from_arr([u128::MAX - 2, 2]));
+
+    // Multiplication
+    let one = u256::from(1);
+    assert_eq!((one << 200) * (one << 50), one << 250);
+    assert_eq!(u256::MAX * 2, u256::MAX);
+    assert_eq!(u256::MAX.widening_mul(u256::MAX), (one, u256::MAX - 1));
+    assert_eq!((one << 255).widening_mul(u256::from(4)), (u256::MIN, u256::from(2)));
+    assert_eq!(u256::MAX.wrapping_mul(u256::from(2)), u256::MAX - 1);
+    assert_eq!(u256::MAX.checked_mul(u256::from(2)), None);
+    assert_eq!(u256::from(u128::MAX).overflowing_mul(u256::from(u128::MAX)), (u256::from_mul(u128::MAX, u128::MAX), false));
+
+    // Division and Remainder
+    let (x, y) = (12345678901234567890123456789u128, u128::MAX - 5);
+    assert_eq!((one << 255) / (one << 127), one << 128);
+    assert_eq!(u256::MAX / u256::MAX, one);
+    assert_eq!(u256::MAX % (one << 128), u128::MAX);
+    assert_eq!(u256::from_mul(x, y) / y, x);
+    assert_eq!((u256::from_mul(x, y) + 7).div_rem(u256::from(y)), (u256::from(x), u256::from(7)));
+    assert_eq!(u256::from(100) / 7, 14);
+    assert_eq!(u256::from(100) % 7, 2);
+    assert_eq!(u256::MAX.checked_div(u256::MIN), None);
+    assert_eq!(u256::MAX.checked_rem(u256::MIN), None);
+
+    // Checked, Wrapping and Overflowing
+    assert_eq!(u256::MAX.overflowing_add(one), (u256::MIN, true));
+    assert_eq!(u256::MIN.overflowing_sub(one), (u256::MAX, true));
+    assert_eq!(u256::from(u128::MAX).wrapping_add(one), one << 128);
+    assert_eq!(u256::MIN.wrapping_sub(one), u256::MAX);
+    assert_eq!(u256::MIN.checked_sub(one), None);
+    assert_eq!(u256::MAX.checked_add(u256::MIN), Some(u256::MAX));
+    assert_eq!(one.wrapping_neg(), u256::MAX);
+    assert_eq!(one.overflowing_shl(256), (one, true));
+    assert_eq!(one.checked_shl(255), Some(one << 255));
+    assert_eq!(one.checked_shr(256), None);
+    assert_eq!(u256::from(2).pow(255), one << 255);
+    assert_eq!(u256::from(2).checked_pow(256), None);
+    assert_eq!(u256::from(2).wrapping_pow(256), u256::MIN);
+    assert_eq!(u256::from(3).saturating_pow(200), u256::MAX);
+    assert_eq!(u256::from(7).abs_diff(u256::from(10)), 3);
+
+    // Bit counting
+    assert_eq!(u256::MIN.leading_zeros(), 256);
+    assert_eq!(u256::MIN.trailing_zeros(), 256);
+    assert_eq!(one.leading_zeros(), 255);
+    assert_eq!((one << 200).trailing_zeros(), 200);
+    assert_eq!((one << 200).leading_zeros(), 55);
+    assert_eq!(u256::MAX.count_ones(), 256);
+    assert_eq!((u256::MAX >> 3).count_zeros(), 3);
+    assert_eq!((u256::MAX >> 3).leading_ones(), 0);
+    assert_eq!((u256::MAX << 130).leading_ones(), 126);
+    assert_eq!(u256::MAX.trailing_ones(), 256);
+    assert_eq!(u256::MAX.ilog2(), 255);
+    assert_eq!(u256::MAX.isqrt(), u256::from(u128::MAX));
+    assert_eq!(u256::from_mul(u128::MAX, u128::MAX).isqrt(), u256::from(u128::MAX));
+    assert_eq!((u256::from_mul(u128::MAX, u128::MAX) - 1).isqrt(), u256::from(u128::MAX - 1));
+    assert_eq!(u256::from(99).isqrt(), u256::from(9));
+    assert!((one << 130).is_power_of_two());
+    assert!(!u256::from(6).is_power_of_two());
+
+    // Formatting
+    assert_eq!(u256::MAX.to_string(), "115792089237316195423570985008687907853269984665640564039457584007913129639935");
+    assert_eq!(u256::MIN.to_string(), "0");
+    assert_eq!(u256::from(u128::MAX).to_string(), u128::MAX.to_string());
+    assert_eq!(format!("{:x}", u256::MAX), "f".repeat(64));
+    assert_eq!(format!("{:#X}", one << 128), format!("0x1{}", "0".repeat(32)));
+    assert_eq!(format!("{:b}", u256::from(10)), "1010");
+    assert_eq!(format!("{:#o}", u256::from(64)), "0o100");
+    assert_eq!(format!("{:>6}", u256::from(42)), "    42");
+    assert_eq!(format!("{:06}", u256::from(42)), "000042");
+    assert_eq!((one << 255).to_string_radix(2), format!("1{}", "0".repeat(255)));
+    assert_eq!(u256::from(35).to_string_radix(36), "z");
+
+    // Parsing
+    assert_eq!("115792089237316195423570985008687907853269984665640564039457584007913129639935".parse::<u256>(), Ok(u256::MAX));
+    assert_eq!("+42".parse::<u256>(), Ok(u256::from(42)));
+    assert_eq!(u256::from_str_radix(&"f".repeat(64), 16), Ok(u256::MAX));
+    assert_eq!(u256::from_str_radix("Zz", 36), Ok(u256::from(1295)));
+    assert_eq!(u256::from_str_radix(&(one << 200).to_string_radix(7), 7), Ok(one << 200));
+    assert_eq!("".parse::<u256>().unwrap_err().kind(), &ParseErrorKind::Empty);
+    assert_eq!("-1".parse::<u256>().unwrap_err().kind(), &ParseErrorKind::InvalidDigit);
+    assert_eq!("12a".parse::<u256>().unwrap_err().kind(), &ParseErrorKind::InvalidDigit);
+    assert_eq!("115792089237316195423570985008687907853269984665640564039457584007913129639936".parse::<u256>().unwrap_err().kind(), &ParseErrorKind::PosOverflow);
+}
+#[test]
+/// Run all unit tests for i256 types
+pub fn i256_tests() {
+    // General type tests
+    let one = i256::from(1);
+    assert_eq!(i256::from(-1), i256::from_arr([u128::MAX, u128::MAX]));
+    assert_eq!(i128::from(i256::from(-5)), -5);
+    assert_eq!(u256::from(i256::from(-1)), u256::MAX);
+    assert_eq!(i256::from(u256::MAX), -1);
+    assert_eq!(i256::MIN.to_string(), "-57896044618658097711785492504343953926634992332820282019728792003956564819968");
+    assert_eq!(i256::MAX.to_string(), "57896044618658097711785492504343953926634992332820282019728792003956564819967");
+    assert_eq!(format!("{:+}", i256::from(42)), "+42");
+
+    // Comparisons
+    assert!(i256::MIN < i256::from(-1));
+    assert!(i256::from(-1) < i256::from(0));
+    assert!(i256::from(i128::MAX) < one << 127);
+    assert!(i256::MAX > one << 254);
+
+    // Exact products
+    assert_eq!(i256::from_mul(-3, 5), -15);
+    assert_eq!(i256::from_mul(i128::MIN, i128::MIN), one << 254);
+    assert_eq!(i256::from_mul(i128::MIN, i128::MAX), -(i256::from_mul(i128::MAX, i128::MAX) + i256::from(i128::MAX)));
+
+    // Arithmetic
+    assert_eq!(i256::MAX + one, i256::MAX);
+    assert_eq!(i256::MIN - one, i256::MIN);
+    assert_eq!(i256::MAX.overflowing_add(one), (i256::MIN, true));
+    assert_eq!(i256::from(-7).checked_sub(i256::MAX), None);
+    assert_eq!(-i256::MIN, i256::MAX);
+    assert_eq!(i256::MIN.wrapping_neg(), i256::MIN);
+    assert_eq!(i256::MIN.unsigned_abs(), u256::from(1) << 255);
+    assert_eq!((one << 254) * i256::from(2), i256::MAX);
+    assert_eq!((one << 254).overflowing_mul(i256::from(-2)), (i256::MIN, false));
+    assert_eq!(i256::from(-4) * i256::from(-5), 20);
+    assert_eq!(i256::from(-7) / i256::from(2), -3);
+    assert_eq!(i256::from(-7) % i256::from(2), -1);
+    assert_eq!(i256::from(7) % i256::from(-2), 1);
+    assert_eq!(i256::MIN / i256::from(-1), i256::MAX);
+    assert_eq!(i256::MIN.checked_div(i256::from(-1)), None);
+    assert_eq!(i256::MAX.checked_div(i256::from(0)), None);
+    assert_eq!(i256::from(-9).signum(), -1);
+
+    // Arithmetic shift right
+    assert_eq!(i256::from(-8) >> 2, -2);
+    assert_eq!(i256::from(-7) >> 1, -4);
+    assert_eq!(i256::from(8) >> 2, 2);
+    assert_eq!(i256::MIN >> 255, -1);
+    assert_eq!(i256::from(-1) >> 200, -1);
+    assert_eq!(i256::from(-1) << 255, i256::MIN);
+
+    // Conversions with signed 128-bit types
+    assert_eq!(i256::from(c128::MIN), i128::MIN);
+    assert_eq!(c128::from(i256::from(c128::PI)), c128::PI);
+    assert_eq!(c128::from(i256::from(c128::MAX) + one), c128::MAX);
+    assert_eq!(c128::from(i256::MIN), c128::MIN);
+    assert_eq!(c128::MIN * c128::from(0.5), c128::from(-16.0));
+    assert_eq!(c128::from(-1.5) * c128::from(2.5), c128::from(-3.75));
+}
+
+#[test]
+/// Run all unit tests for division of types
+pub fn division_tests() {
+    // Exact quotients
+    assert_eq!(c8::from(0.25) / c8::from(0.5), c8::from(0.5));
+    assert_eq!(c8::from(-0.25) / c8::from(0.5), c8::from(-0.5));
+    assert_eq!(c16::from(0.75) / c16::from(-1.5), c16::from(-0.5));
+    assert_eq!(c32::from(-3.0) / c32::from(-0.5), c32::from(6.0));
+    assert_eq!(c64::from(7.5) / c64::from(2.5), c64::from(3.0));
+    assert_eq!(c128::from(-7.5) / c128::from(2.5), c128::from(-3.0));
+    assert_eq!(uc8::from(3.0) / uc8::from(2.0), uc8::from(1.5));
+    assert_eq!(uc16::from(0.75) / uc16::from(1.5), uc16::from(0.5));
+    assert_eq!(uc32::from(12.0) / uc32::from(1.5), uc32::from(8.0));
+    assert_eq!(uc64::from(7.5) / uc64::from(2.5), uc64::from(3.0));
+    assert_eq!(uc128::from(7.5) / uc128::from(2.5), uc128::from(3.0));
+
+    // Rounding to nearest
+    assert_eq!(c32::from(1.0) / c32::from(3.0), 0x5555555);
+    assert_eq!(c32::from(2.0) / c32::from(3.0), 0xAAAAAAB);
+    assert_eq!(c32::from(-2.0) / c32::from(3.0), (0xAAAAAABu32).wrapping_neg());
+    assert_eq!(c128::from(1.0) / c128::from(3.0), 0x1555555555555555555555555555555);
+    assert_eq!(uc128::from(2.0) / uc128::from(3.0), 0x2AAAAAAAAAAAAAAAAAAAAAAAAAAAAAB);
+
+    // Ties round to even
+    assert_eq!(uc8::GRN / uc8::from(2.0), uc8::of(0));
+    assert_eq!(uc8::of(3) / uc8::from(2.0), uc8::of(2));
+    assert_eq!(c16::of(5) / c16::from(2.0), c16::of(2));
+
+    // Saturation
+    assert_eq!(c8::from(1.5) / c8::from(0.5), c8::MAX);
+    assert_eq!(c8::from(-1.5) / c8::from(0.5), c8::MIN);
+    assert_eq!(c128::MAX / c128::from(0.5), c128::MAX);
+    assert_eq!(c128::MIN / c128::from(0.5), c128::MIN);
+    assert_eq!(uc64::MAX / uc64::from(0.5), uc64::MAX);
+    assert_eq!(uc128::MAX / uc128::from(0.5), uc128::MAX);
+
+    // Division by zero
+    assert_eq!(c32::from(0.5) / c32::from(0.0), c32::MAX);
+    assert_eq!(c32::from(-0.5) / c32::from(0.0), c32::MIN);
+    assert_eq!(c32::from(0.0) / c32::from(0.0), c32::from(0.0));
+    assert_eq!(c128::from(-0.5) / c128::from(0.0), c128::MIN);
+    assert_eq!(uc16::from(0.5) / uc16::from(0.0), uc16::MAX);
+    assert_eq!(uc128::from(0.0) / uc128::from(0.0), uc128::from(0.0));
+}
+
+#[test]
+/// Run all unit tests for remainder of types
+pub fn remainder_tests() {
+    assert_eq!(c8::from(1.5) % c8::from(0.5), c8::from(0.0));
+    assert_eq!(c16::from(3.75) % c16::from(1.5), c16::from(0.75));
+    assert_eq!(c32::from(5.5) % c32::from(2.0), c32::from(1.5));
+    assert_eq!(c32::from(-5.5) % c32::from(2.0), c32::from(-1.5));
+    assert_eq!(c64::from(5.5) % c64::from(-2.0), c64::from(1.5));
+    assert_eq!(c128::from(-5.5) % c128::from(-2.0), c128::from(-1.5));
+    assert_eq!(c128::MIN % c128::of(u128::MAX), c128::from(0.0));
+    assert_eq!(uc8::from(3.75) % uc8::from(1.5), uc8::from(0.75));
+    assert_eq!(uc32::from(15.5) % uc32::from(4.0), uc32::from(3.5));
+    assert_eq!(uc128::from(5.5) % uc128::from(2.0), uc128::from(1.5));
+
+    // Remainder by zero
+    assert_eq!(c32::from(5.5) % c32::from(0.0), c32::from(0.0));
+    assert_eq!(uc64::from(5.5) % uc64::from(0.0), uc64::from(0.0));
+}
+
+#[test]
+/// Constants Tests for quartas, dimids and acutes
+pub fn family_constants_tests() {
+    assert_eq!(f64::from(q8::MIN),    q8::MINF);
+    assert_eq!(f64::from(q8::MAX),    q8::MAXF);
+    assert_eq!(f64::from(uq8::MIN),   uq8::MINF);
+    assert_eq!(f64::from(uq8::MAX),   uq8::MAXF);
+    assert_eq!(f64::from(q16::MIN),   q16::MINF);
+    assert_eq!(f64::from(q16::MAX),   q16::MAXF);
+    assert_eq!(f64::from(uq16::MIN),  uq16::MINF);
+    assert_eq!(f64::from(uq16::MAX),  uq16::MAXF);
+    assert_eq!(f64::from(q32::MIN),   q32::MINF);
+    assert_eq!(f64::from(q32::MAX),   q32::MAXF);
+    assert_eq!(f64::from(uq32::MIN),  uq32::MINF);
+    assert_eq!(f64::from(uq32::MAX),  uq32::MAXF);
+    assert_eq!(f64::from(q64::MIN),   q64::MINF);
+    assert!(q64::from(q64::MAXF) < q64::MAX && q64::MAXF < f64::from(q64::MAX));
+    assert_eq!(f64::from(uq64::MIN),  uq64::MINF);
+    assert!(uq64::from(uq64::MAXF) < uq64::MAX && uq64::MAXF < f64::from(uq64::MAX));
+    assert_eq!(f64::from(q128::MIN),  q128::MINF);
+    assert!(q128::from(q128::MAXF) < q128::MAX && q128::MAXF < f64::from(q128::MAX));
+    assert_eq!(f64::from(uq128::MIN), uq128::MINF);
+    assert!(uq128::from(uq128::MAXF) < uq128::MAX && uq128::MAXF < f64::from(uq128::MAX));
+    assert_eq!(f64::from(d8::MIN),    d8::MINF);
+    assert_eq!(f64::from(d8::MAX),    d8::MAXF);
+    assert_eq!(f64::from(ud8::MIN),   ud8::MINF);
+    assert_eq!(f64::from(ud8::MAX),   ud8::MAXF);
+    assert_eq!(f64::from(d16::MIN),   d16::MINF);
+    assert_eq!(f64::from(d16::MAX),   d16::MAXF);
+    assert_eq!(f64::from(ud16::MIN),  ud16::MINF);
+    assert_eq!(f64::from(ud16::MAX),  ud16::MAXF);
+    assert_eq!(f64::from(d32::MIN),   d32::MINF);
+    assert_eq!(f64::from(d32::MAX),   d32::MAXF);
+    assert_eq!(f64::from(ud32::MIN),  ud32::MINF);
+    assert_eq!(f64::from(ud32::MAX),  ud32::MAXF);
+    assert_eq!(f64::from(d64::MIN),   d64::MINF);
+    assert!(d64::from(d64::MAXF) < d64::MAX && d64::MAXF < f64::from(d64::MAX));
+    assert_eq!(f64::from(ud64::MIN),  ud64::MINF);
+    assert!(ud64::from(ud64::MAXF) < ud64::MAX && ud64::MAXF < f64::from(ud64::MAX));
+    assert_eq!(f64::from(d128::MIN),  d128::MINF);
+    assert!(d128::from(d128::MAXF) < d128::MAX && d128::MAXF < f64::from(d128::MAX));
+    assert_eq!(f64::from(ud128::MIN), ud128::MINF);
+    assert!(ud128::from(ud128::MAXF) < ud128::MAX && ud128::MAXF < f64::from(ud128::MAX));
+    assert_eq!(f64::from(a8::MIN),    a8::MINF);
+    assert_eq!(f64::from(a8::MAX),    a8::MAXF);
+    assert_eq!(f64::from(ua8::MIN),   ua8::MINF);
+    assert_eq!(f64::from(ua8::MAX),   ua8::MAXF);
+    assert_eq!(f64::from(a16::MIN),   a16::MINF);
+    assert_eq!(f64::from(a16::MAX),   a16::MAXF);
+    assert_eq!(f64::from(ua16::MIN),  ua16::MINF);
+    assert_eq!(f64::from(ua16::MAX),  ua16::MAXF);
+    assert_eq!(f64::from(a32::MIN),   a32::MINF);
+    assert_eq!(f64::from(a32::MAX),   a32::MAXF);
+    assert_eq!(f64::from(ua32::MIN),  ua32::MINF);
+    assert_eq!(f64::from(ua32::MAX),  ua32::MAXF);
+    assert_eq!(f64::from(a64::MIN),   a64::MINF);
+    assert!(a64::from(a64::MAXF) < a64::MAX && a64::MAXF < f64::from(a64::MAX));
+    assert_eq!(f64::from(ua64::MIN),  ua64::MINF);
+    assert!(ua64::from(ua64::MAXF) < ua64::MAX && ua64::MAXF < f64::from(ua64::MAX));
+    assert_eq!(f64::from(a128::MIN),  a128::MINF);
+    assert!(a128::from(a128::MAXF) < a128::MAX && a128::MAXF < f64::from(a128::MAX));
+    assert_eq!(f64::from(ua128::MIN), ua128::MINF);
+    assert!(ua128::from(ua128::MAXF) < ua128::MAX && ua128::MAXF < f64::from(ua128::MAX));
+    assert_eq!(f64::from(uq8::PI),  3.140625);
+    assert_eq!(f64::from(q16::PI),  3.141357421875);
+    assert_eq!(f64::from(d8::PI),   3.125);
+    assert_eq!(f64::from(d16::E),   2.71484375);
+    assert_eq!(f64::from(ud32::PI), 3.1415863037109375);
+    assert_eq!(f64::from(d64::PI),  3.1415926534682512);
+    assert_eq!(f64::from(q128::E),  2.718281828459045);
+}
+
+
+#[test]
+/// Run unit tests for interpreting quartas, dimids and acutes
+pub fn family_interpretation_tests() {
+    let pi64 = f64::consts::PI;
+
+    assert_eq!(q8::from(pi64),      q8::MAX);
+    assert_eq!(q32::from(pi64),     0x3243F6B);
+    assert_eq!(d32::from(pi64),     0x3243F);
+    assert_eq!(ud32::from(pi64),    0x3243F);
+    assert_eq!(a16::from(pi64),     a16::MAX);
+    assert_eq!(a16::from(-pi64),    a16::MIN);
+    assert_eq!(ua16::from(pi64),    ua16::MAX);
+    assert_eq!(a16::from(0.7),      0x599A);
+    assert_eq!(ua16::from(0.7),     0xB333);
+    assert_eq!(a64::from(0.7),      0x5999999999999800);
+    assert_eq!(q128::from(0.7),     0xB33333333333300000000000);
+    assert_eq!(ud128::from(0.7),    0xB333333333333000);
+    assert_eq!(f64::from(a64::from(0.7)),  0.7);
+    assert_eq!(f64::from(d128::from(-12345.6789)), -12345.6789);
+    assert_eq!(f64::from(uq64::from(30000.125)), 30000.125);
+}
+
+#[test]
+/// Run algebra tests for quartas, dimids and acutes
+pub fn family_algebra_tests() {
+    assert_eq!(q16::from(-3.5) + q16::from(1.25), q16::from(-2.25));
+    assert_eq!(uq32::from(200.5) - uq32::from(0.75), uq32::from(199.75));
+    assert_eq!(d32::from(100.5) * d32::from(-2.0), d32::from(-201.0));
+    assert_eq!(ud64::from(100.5) * ud64::from(2.0), ud64::from(201.0));
+    assert_eq!(d128::from(-1.5) * d128::from(-1.5), d128::from(2.25));
+    assert_eq!(q128::from(201.0) / q128::from(-2.0), q128::from(-100.5));
+    assert_eq!(d16::from(7.5) % d16::from(2.0), d16::from(1.5));
+
+    // Acutes never leave the -1 to 1 range when multiplied
+    assert_eq!(a8::from(0.5) * a8::from(0.5), a8::from(0.25));
+    assert_eq!(a32::from(-0.5) * a32::from(0.5), a32::from(-0.25));
+    assert_eq!(a128::from(-0.5) * a128::from(-0.5), a128::from(0.25));
+    assert_eq!(ua8::from(0.5) * ua8::from(0.5), ua8::from(0.25));
+    assert_eq!(ua128::from(0.75) * ua128::from(0.5), ua128::from(0.375));
+    assert_eq!(a16::MAX * a16::MAX, a16::of(0x7FFE));
+    assert_eq!(ua64::MAX * ua64::MAX, ua64::of(u64::MAX - 1));
+
+    assert_eq!(a16::from(0.25) + a16::from(0.5), a16::from(0.75));
+    assert_eq!(a16::from(0.75) + a16::from(0.5), a16::MAX);
+    assert_eq!(ua32::from(0.25) - ua32::from(0.5), ua32::MIN);
+    assert_eq!(a64::from(0.25) / a64::from(0.5), a64::from(0.5));
+    assert_eq!(ua16::from(0.25) / ua16::from(0.5), ua16::from(0.5));
+
+    // Negation
+    assert_eq!(-a8::from(0.5), a8::from(-0.5));
+    assert_eq!(-a8::from(0.0), a8::from(0.0));
+    assert_eq!(-a8::MIN, a8::MAX);
+    assert_eq!(-d64::from(-1234.5), d64::from(1234.5));
+}
+
+#[test]
+/// Conversion and comparison tests for quartas, dimids and acutes
+pub fn family_conversion_tests() {
+    assert_eq!(q128::from(q8::MIN),  q128::from(-2.0));
+    assert_eq!(q8::from(q128::MAX),  q8::MAX);
+    assert_eq!(q64::from(q16::from(-5.75)), q64::from(-5.75));
+    assert_eq!(d16::from(d64::from(-100.25)), d16::from(-100.25));
+    assert_eq!(ud128::from(ud8::from(9.5)), ud128::from(9.5));
+    assert_eq!(a128::from(a8::from(-0.75)), a128::from(-0.75));
+    assert_eq!(a8::from(a32::from(-0.75)), a8::from(-0.75));
+    assert_eq!(a8::from(a32::from(0.75)), a8::from(0.75));
+    assert_eq!(ua8::from(ua64::from(0.75)), ua8::from(0.75));
+    assert_eq!(ua64::from(ua16::from(0.125)), ua64::from(0.125));
+    assert_eq!(ud32::from(d32::from(5.0)), ud32::from(5.0));
+    assert_eq!(uc128::from(uc8::from(1.5)), uc128::from(1.5));
+    assert_eq!(uc8::from(uc32::from(1.5)), uc8::from(1.5));
+
+    assert_eq!(q32::from(-100.0) < q32::from(100.0), true);
+    assert_eq!(d128::from(-0.5) <= d128::from(-0.25), true);
+    assert_eq!(a16::from(0.5) > a16::from(-0.5), true);
+    assert_eq!(ua128::from(0.5) >= ua128::from(0.25), true);
+}
+
+
+#[test]
+/// Tests for arbitrary layouts of the generic types
+pub fn generic_layout_tests() {
+    type f12 = Fixed<u32, 20>;
+    type uf12 = UFixed<u32, 20>;
+
+    // Named layouts are aliases of the generic types
+    assert_eq!(Fixed::<u32, 28>::PI, c32::PI);
+    assert_eq!(UFixed::<u64, 32>::MAX, ud64::MAX);
+    assert_eq!(c32::FRC, 28);
+    assert_eq!(c32::DEC, 4);
+    assert_eq!(uc32::DEC, 4);
+    assert_eq!(ua8::FRC, 8);
+    // One saturates when it is out of range
+    assert_eq!(a8::ONE, a8::MAXB);
+    assert_eq!(a8::of(a8::ONE), a8::MAX);
+    assert_eq!(ua8::ONE, u8::MAX);
+    assert_eq!(ua8::of(ua8::ONE), ua8::MAX);
+    assert_eq!(ua128::ONE, u128::MAX);
+    assert_eq!(c8::ONE, 0x40);
+    assert_eq!(c32::ONE, 0x10000000);
+    assert_eq!(c8::u64_round(0x0180_0000_0000_0000), 0x02);
+    assert_eq!(c32::u64_round(0x1234_5678_8000_0000), 0x12345679);
+    assert_eq!(uc16::u64_round(0xABCD_7FFF_FFFF_FFFF), 0xABCD);
+    assert_eq!(c64::u64_round(u64::MAX), u64::MAX);
+    assert_eq!(uc128::u64_round(u64::MAX), u64::MAX as u128);
+
+    // 1 Sign bit, 11 Integer bits, 20 Fraction bits
+    assert_eq!(f12::MINF, -2048.0);
+    assert_eq!(f12::MAXF, 2048.0 - 1.0 / 1048576.0);
+    assert_eq!(f12::from(-1000.5) + f12::from(24.25), f12::from(-976.25));
+    assert_eq!(f12::from(-40.5) * f12::from(2.0), f12::from(-81.0));
+    assert_eq!(f12::from(81.0) / f12::from(-2.0), f12::from(-40.5));
+    assert_eq!(f12::from(3000.0), f12::MAX);
+    assert_eq!(f12::from(-1.5).components(), (-1, 0x7FE, 0x80000000));
+    assert_eq!(f64::from(f12::from(1234.5678)), 1234.5677995681763);
+
+    // 12 Integer bits, 20 Fraction bits
+    assert_eq!(uf12::MAXF, 4096.0 - 1.0 / 1048576.0);
+    assert_eq!(uf12::from(4000.25) - uf12::from(0.5), uf12::from(3999.75));
+    assert_eq!(uf12::from(12.5).components(), (12, 0x80000000));
+
+    // Conversions between storage types and fraction widths
+    assert_eq!(f12::from(c16::from(-1.25)), f12::from(-1.25));
+    assert_eq!(c16::from(f12::from(-1.25)), c16::from(-1.25));
+    assert_eq!(c16::from(f12::from(100.0)), c16::MAX);
+    assert_eq!(c16::from(f12::from(-100.0)), c16::MIN);
+    assert_eq!(uc8::from(uf12::from(1000.0)), uc8::MAX);
+    assert_eq!(f12::from(-1.25).rescale::<28>(), c32::from(-1.25));
+    assert_eq!(f12::from(-100.0).rescale::<28>(), c32::MIN);
+    assert_eq!(c32::from(-1.25).rescale::<20>(), f12::from(-1.25));
+    assert_eq!(uc32::from(1.25).rescale::<20>(), uf12::from(1.25));
+    assert_eq!(uf12::from(20.0).rescale::<28>(), uc32::MAX);
+}
+
+#[test]
+/// Tests for generic code written against the FixedPoint trait
+pub fn fixed_point_tests() {
+    /// Value of the lowest integer bit, built only through the trait
+    fn one_bit<T: FixedPoint<Bits = u32>>() -> T {
+        T::from_bits(1 << T::FRAC)
+    }
+    assert_eq!(c32::FRAC, 28);
+    assert_eq!(<Fixed<u32, 20>>::FRAC, 20);
+    assert_eq!(uc128::FRAC, 122);
+    assert_eq!(one_bit::<c32>(), c32::from(1.0));
+    assert_eq!(one_bit::<uc32>(), uc32::from(1.0));
+    assert_eq!(one_bit::<Fixed<u32, 20>>(), Fixed::<u32, 20>::from(1.0));
+    assert_eq!(c32::PI.to_bits(), c32::PI.bits);
+    assert_eq!(<c8 as FixedPoint>::from_bits(0x40), c8::from(1.0));
+    assert_eq!(<uq16 as FixedPoint>::from_bits(uq16::MAX.to_bits()), uq16::MAX);
+}
+
+#[test]
+/// Tests for exact decimal formatting and parsing
+pub fn decimal_tests() {
+    // Exact expansions
+    assert_eq!(format!("{}", c8::MAX), "1.984375");
+    assert_eq!(format!("{}", c8::MIN), "-2");
+    assert_eq!(format!("{}", c64::MIN), "-16");
+    assert_eq!(format!("{}", uc8::MIN), "0");
+    assert_eq!(format!("{}", c32::PI), "3.1415926516056060791015625");
+    assert_eq!(format!("{}", c128::MAX), "31.99999999999999999999999999999999999981192090386843399872500215404444069154901351091646599655859972699545323848724365234375");
+    assert_eq!(format!("{}", ua128::MAX), "0.99999999999999999999999999999999999999706126412294428123007815865694438580545333610806978119622812073430395685136318206787109375");
+    assert_eq!(format!("{}", c16::from(-0.75)), "-0.75");
+
+    // Precision rounds to nearest, ties to even
+    assert_eq!(format!("{:.2}", c32::PI), "3.14");
+    assert_eq!(format!("{:.3}", c8::MAX), "1.984");
+    assert_eq!(format!("{:.1}", c8::MAX), "2.0");
+    assert_eq!(format!("{:.0}", c8::from(0.5)), "0");
+    assert_eq!(format!("{:.0}", c8::from(1.5)), "2");
+    assert_eq!(format!("{:.1}", c8::from(-0.25)), "-0.2");
+    assert_eq!(format!("{:.1}", c8::from(-0.75)), "-0.8");
+    assert_eq!(format!("{:.4}", uc8::from(1.5)), "1.5000");
+
+    // Width, fill and sign flags
+    assert_eq!(format!("{:>8.2}", c32::PI), "    3.14");
+    assert_eq!(format!("{:08.2}", c32::from(-3.0)), "-0003.00");
+    assert_eq!(format!("{:+}", c8::from(1.0)), "+1");
+
+    // Parsing
+    assert_eq!("1.984375".parse::<c8>(), Ok(c8::MAX));
+    assert_eq!("-2".parse::<c8>(), Ok(c8::MIN));
+    assert_eq!("+0.5".parse::<c8>(), Ok(c8::from(0.5)));
+    assert_eq!(".5".parse::<uc8>(), Ok(uc8::from(0.5)));
+    assert_eq!("3.".parse::<uc8>(), Ok(uc8::from(3.0)));
+    assert_eq!("-2.005".parse::<c8>(), Ok(c8::MIN));
+    assert_eq!("0.0078125".parse::<c8>(), Ok(c8::of(0)));
+    assert_eq!("0.0234375".parse::<c8>(), Ok(c8::of(2)));
+    assert_eq!("0.00781251".parse::<c8>(), Ok(c8::of(1)));
+    assert_eq!("-0".parse::<uc8>(), Ok(uc8::MIN));
+    assert_eq!("3.1415926516056060791015625".parse::<c32>(), Ok(c32::PI));
+    assert_eq!(c128::PI.to_string().parse::<c128>(), Ok(c128::PI));
+    assert_eq!(ua128::MAX.to_string().parse::<ua128>(), Ok(ua128::MAX));
+    assert_eq!(c64::MIN.to_string().parse::<c64>(), Ok(c64::MIN));
+
+    // Parse errors
+    assert_eq!("".parse::<c8>().unwrap_err().kind(), &ParseErrorKind::Empty);
+    assert_eq!("-.".parse::<c8>().unwrap_err().kind(), &ParseErrorKind::Empty);
+    assert_eq!("1.2.3".parse::<c8>().unwrap_err().kind(), &ParseErrorKind::InvalidDigit);
+    assert_eq!("1e5".parse::<c8>().unwrap_err().kind(), &ParseErrorKind::InvalidDigit);
+    assert_eq!("2".parse::<c8>().unwrap_err().kind(), &ParseErrorKind::PosOverflow);
+    assert_eq!("1.9921875".parse::<c8>().unwrap_err().kind(), &ParseErrorKind::PosOverflow);
+    assert_eq!("-2.01".parse::<c8>().unwrap_err().kind(), &ParseErrorKind::NegOverflow);
+    assert_eq!("-0.1".parse::<uc8>().unwrap_err().kind(), &ParseErrorKind::NegOverflow);
+    assert_eq!("1".parse::<ua128>().unwrap_err().kind(), &ParseErrorKind::PosOverflow);
+}
+
+#[test]
+/// Tests for the arbitrary length number and exact conversions through it
+pub fn aln_tests() {
+    // Parsing and printing
+    assert_ne!(ALN::from("0.05"), ALN::from("0.5"));
+    assert_eq!(ALN::from("0.05").to_string(), "0.05");
+    assert_eq!(ALN::from("1234567890.0987654321").to_string(), "1234567890.0987654321");
+    assert_eq!(ALN::from("-000120.500").to_string(), "-120.5");
+    assert_eq!(ALN::from("100").to_string(), "100");
+    assert_eq!(ALN::from("-0.0").to_string(), "0");
+    assert_eq!(ALN::rectify_string(String::from("00100.0100")), "100.01");
+    assert_eq!(format!("{:.3}", ALN::from("2.0005")), "2.000");
+    assert_eq!(format!("{:.3}", ALN::from("2.0015")), "2.002");
+    assert_eq!(format!("{:.2}", ALN::from("-9.999")), "-10.00");
+    assert_eq!(format!("{:.4}", ALN::from("1.5")), "1.5000");
+
+    // Ordering
+    assert!(ALN::from("-1.5") < ALN::from("-1.25"));
+    assert!(ALN::from("0.05") < ALN::from("0.5"));
+    assert_eq!(ALN::from("0.5"), ALN::from("0.50"));
+
+    // Exact conversion from every layout
+    assert_eq!(ALN::from(c32::PI).to_string(), "3.1415926516056060791015625");
+    assert_eq!(ALN::from(c128::MAX).to_string(), c128::MAX.to_string());
+    assert_eq!(ALN::from(c64::MIN).to_string(), "-16");
+    assert_eq!(ALN::from(ua128::MAX).to_string(), ua128::MAX.to_string());
+    assert_eq!(c128::from_aln(&ALN::from(c128::MIN), RoundingMode::NearestEven), c128::MIN);
+    assert_eq!(uq64::from_aln(&ALN::from(uq64::MAX), RoundingMode::TowardZero), uq64::MAX);
+    assert!(ua128::from_aln_report(&ALN::from(ua128::MAX), RoundingMode::NearestEven).1.is_exact());
+
+    // Rounding modes
+    let tie = ALN::from("0.0234375"); // 1.5 ULP of c8
+    assert_eq!(c8::from_aln(&tie, RoundingMode::NearestEven), c8::of(2));
+    assert_eq!(c8::from_aln(&tie, RoundingMode::NearestAway), c8::of(2));
+    assert_eq!(c8::from_aln(&tie, RoundingMode::TowardZero), c8::of(1));
+    assert_eq!(c8::from_aln(&tie, RoundingMode::TowardPositive), c8::of(2));
+    assert_eq!(c8::from_aln(&tie, RoundingMode::TowardNegative), c8::of(1));
+    let tie = ALN::from("-0.0234375");
+    assert_eq!(c8::from_aln(&tie, RoundingMode::NearestEven), -c8::of(2));
+    assert_eq!(c8::from_aln(&tie, RoundingMode::TowardZero), -c8::of(1));
+    assert_eq!(c8::from_aln(&tie, RoundingMode::TowardPositive), -c8::of(1));
+    assert_eq!(c8::from_aln(&tie, RoundingMode::TowardNegative), -c8::of(2));
+    let tie = ALN::from("0.0078125"); // 0.5 ULP of c8
+    assert_eq!(c8::from_aln(&tie, RoundingMode::NearestEven), c8::of(0));
+    assert_eq!(c8::from_aln(&tie, RoundingMode::NearestAway), c8::of(1));
+
+    // Saturation
+    let (val, report) = c8::from_aln_report(&ALN::from("5"), RoundingMode::NearestEven);
+    assert_eq!(val, c8::MAX);
+    assert!(report.saturated);
+    assert_eq!(uc8::from_aln(&ALN::from("-1"), RoundingMode::NearestEven), uc8::MIN);
+    assert_eq!(c8::from_aln(&ALN::from("-1.99"), RoundingMode::TowardNegative), c8::MIN);
+    assert_eq!(c8::from_aln(&ALN::from("-1.99"), RoundingMode::TowardZero), c8::from(-1.984375));
+
+    // Rounding error reports
+    let (val, report) = c8::from_aln_report(&ALN::from("0.01"), RoundingMode::NearestEven);
+    assert_eq!(val, c8::of(1));
+    assert_eq!(report.error.to_string(), "0.005625");
+    assert_eq!(report.ulps.to_string(), "0.36");
+    assert_eq!(report.direction, Ordering::Greater);
+    assert!(report.within_half_ulp());
+
+    // Auditing the constant table: c32::PI is truncated rather than rounded
+    let pi = ALN::from("3.14159265358979323846264338327950288419716939937510");
+    let (val, report) = c32::from_aln_report(&pi, RoundingMode::NearestEven);
+    assert_eq!(val, c32::of(0x3243F6A9));
+    assert!(report.within_half_ulp());
+    assert_eq!(c32::from_aln(&pi, RoundingMode::TowardZero), c32::PI);
+    assert!(!pi.report(ALN::from(c32::PI), 28, false).within_half_ulp());
+}
+
+#[test]
+/// Tests for exact accumulation of products in a Quire
+pub fn quire_tests() {
+    let grn = c8::of(1);
+
+    // Products far below one ULP add up exactly
+    let mut quire = Quire::<c8>::new();
+    for _ in 0..64 {
+        quire.add_product(grn, grn);
+    }
+    assert_eq!(quire.value(), c8::of(1));
+    assert_eq!(grn * grn, c8::of(0));
+
+    // Rounded once, at the end
+    let mut quire = Quire::<c8>::new();
+    for _ in 0..32 {
+        quire.add_product(grn, grn);
+    }
+    assert_eq!(quire.value(), c8::of(0));
+    assert_eq!(quire.value_round(RoundingMode::NearestAway), c8::of(1));
+    assert_eq!(quire.value_round(RoundingMode::TowardPositive), c8::of(1));
+    for _ in 0..64 {
+        quire.add_product(grn, grn);
+    }
+    assert_eq!(quire.value(), c8::of(2));
+    quire.clear();
+    assert!(quire.is_zero());
+
+    // Dot products
+    assert_eq!(Quire::<c8>::dot(&[c8::from(0.5), c8::from(-1.5)], &[c8::from(1.5), c8::from(0.5)]), c8::from(0.0));
+    assert_eq!(Quire::<c32>::dot(&[c32::from(-2.5), c32::from(0.25), c32::from(3.0)], &[c32::from(1.5), c32::from(-4.0), c32::from(2.0)]), c32::from(1.25));
+    assert_eq!(Quire::<uc16>::dot(&[uc16::from(2.0); 3], &[uc16::from(1.25); 3]), uc16::from(7.5));
+
+    // Intermediate sums may leave the range of the type
+    let mut quire = Quire::<c32>::new();
+    for _ in 0..1000 {
+        quire.add_product(c32::MAX, c32::MAX);
+    }
+    assert_eq!(quire.value(), c32::MAX);
+    for _ in 0..1000 {
+        quire.sub_product(c32::MAX, c32::MAX);
+    }
+    quire.add_value(c32::from(1.25));
+    assert_eq!(quire.value(), c32::from(1.25));
+    quire.sub_value(c32::from(1.5));
+    assert!(quire.is_negative());
+    assert_eq!(quire.value(), c32::from(-0.25));
+    for _ in 0..10 {
+        quire.add_product(c32::MIN, c32::MAX);
+    }
+    assert_eq!(quire.value(), c32::MIN);
+
+    // 128-bit products keep all 256 bits
+    let mut quire = Quire::<c128>::new();
+    quire.add_product(c128::MIN, c128::MIN);
+    quire.add_product(c128::MIN, c128::MIN);
+    quire.sub_product(c128::MIN, c128::MIN);
+    quire.sub_product(c128::MIN, c128::MIN);
+    quire.add_product(c128::of(1), c128::of(1));
+    assert_eq!(quire.value(), c128::of(0));
+    assert_eq!(quire.value_round(RoundingMode::TowardPositive), c128::of(1));
+    assert_eq!(quire.value_round(RoundingMode::TowardNegative), c128::of(0));
+    quire.sub_product(c128::of(1), c128::of(2));
+    assert_eq!(quire.value_round(RoundingMode::TowardNegative), c128::of(u128::MAX));
+
+    let mut quire = Quire::<a128>::new();
+    quire.add_product(a128::MIN, a128::MIN);
+    assert_eq!(quire.value(), a128::MAX);
+    let mut quire = Quire::<ua128>::new();
+    quire.add_product(ua128::MAX, ua128::MAX);
+    assert_eq!(quire.value(), ua128::of(u128::MAX - 1));
+    quire.sub_value(ua128::MAX);
+    assert_eq!(quire.value(), ua128::MIN);
+}
+
+#[test]
+/// Test checked, wrapping, overflowing and saturating arithmetic
+pub fn overflow_tests() {
+    // Addition and subtraction
+    assert_eq!(c8::MAX.checked_add(c8::of(1)), None);
+    assert_eq!(c8::MAX.wrapping_add(c8::of(1)), c8::MIN);
+    assert_eq!(c8::MAX.overflowing_add(c8::of(1)), (c8::MIN, true));
+    assert_eq!(c8::MAX.saturating_add(c8::of(1)), c8::MAX);
+    assert_eq!(c8::MIN.checked_sub(c8::of(1)), None);
+    assert_eq!(c8::of(3).checked_sub(c8::of(1)), Some(c8::of(2)));
+    assert_eq!(uc8::MIN.checked_sub(uc8::of(1)), None);
+    assert_eq!(uc8::MIN.wrapping_sub(uc8::of(1)), uc8::MAX);
+    assert_eq!(uc8::MIN - uc8::of(1), uc8::MIN);
+    assert_eq!(c128::MAX.checked_add(c128::GRN), None);
+    assert_eq!(uc128::MAX.wrapping_add(uc128::GRN), uc128::MIN);
+
+    // Multiplication saturates instead of wrapping
+    assert_eq!(c16::MAX * c16::from(2.0), c16::MAX);
+    assert_eq!(c16::MAX * c16::from(-2.0), c16::MIN);
+    assert_eq!(c16::MIN * c16::from(-1.0), c16::MAX);
+    assert_eq!(c16::MAX.checked_mul(c16::from(2.0)), None);
+    assert_eq!(c16::from(1.5).checked_mul(c16::from(-2.0)), Some(c16::from(-3.0)));
+    assert_eq!(c16::MAX.overflowing_mul(c16::from(2.0)), (c16::of(0xFFFE), true));
+    assert_eq!(c16::MIN.checked_mul(c16::from(1.0)), Some(c16::MIN));
+    assert_eq!(c8::from(-1.0) * c8::from(-1.0), c8::from(1.0));
+    assert_eq!(uc32::MAX * uc32::from(2.0), uc32::MAX);
+    assert_eq!(uc32::MAX.checked_mul(uc32::from(1.0)), Some(uc32::MAX));
+    assert_eq!(c128::MAX * c128::from(2.0), c128::MAX);
+    assert_eq!(c128::MIN * c128::from(2.0), c128::MIN);
+    assert_eq!(c128::MIN.checked_mul(c128::from(-1.0)), None);
+    assert_eq!(c128::from(-1.5).checked_mul(c128::from(2.0)), Some(c128::from(-3.0)));
+    assert_eq!(uc128::MAX * uc128::from(3.0), uc128::MAX);
+    assert_eq!(uc128::MAX.checked_mul(uc128::from(3.0)), None);
+
+    // Division
+    assert_eq!(c16::from(1.0).checked_div(c16::of(0)), None);
+    assert_eq!(c16::MAX.checked_div(c16::from(0.5)), None);
+    assert_eq!(c16::MAX.saturating_div(c16::from(-0.5)), c16::MIN);
+    assert_eq!(c16::from(3.0).checked_div(c16::from(-2.0)), Some(c16::from(-1.5)));
+    assert_eq!(c16::MIN.checked_div(c16::from(-1.0)), None);
+    assert_eq!(uc64::MAX.checked_div(uc64::from(0.5)), None);
+    assert_eq!(uc64::from(0.0).saturating_div(uc64::of(0)), uc64::MIN);
+    assert_eq!(c128::MIN.checked_div(c128::from(-1.0)), None);
+    assert_eq!(c128::MIN.checked_div(c128::from(1.0)), Some(c128::MIN));
+    assert_eq!(uc128::MAX.checked_div(uc128::from(0.5)), None);
+    assert_eq!(uc128::from(6.0).wrapping_div(uc128::from(4.0)), uc128::from(1.5));
+}
+
+#[test]
+/// Test rounded multiplication and narrowing conversions
+pub fn rounding_tests() {
+    // Products of half a grain and one and a half grains
+    assert_eq!(c8::of(1) * c8::from(0.5), c8::of(0));
+    assert_eq!(c8::of(3) * c8::from(0.5), c8::of(2));
+    assert_eq!(c8::of(1).mul_round(c8::from(0.5), RoundingMode::NearestAway), c8::of(1));
+    assert_eq!(c8::of(3).mul_round(c8::from(0.5), RoundingMode::TowardZero), c8::of(1));
+    assert_eq!(c8::of(1).mul_round(c8::from(0.25), RoundingMode::TowardPositive), c8::of(1));
+    assert_eq!(c8::of(3) * c8::from(-0.5), c8::of(0xFE));
+    assert_eq!(c8::of(3).mul_round(c8::from(-0.5), RoundingMode::TowardZero), c8::of(0xFF));
+    assert_eq!(c8::of(3).mul_round(c8::from(-0.5), RoundingMode::TowardPositive), c8::of(0xFF));
+    assert_eq!(c8::of(1).mul_round(c8::from(-0.25), RoundingMode::TowardNegative), c8::of(0xFF));
+    assert_eq!(uc16::of(5) * uc16::from(0.5), uc16::of(2));
+    assert_eq!(uc16::of(5).mul_round(uc16::from(0.5), RoundingMode::TowardPositive), uc16::of(3));
+    assert_eq!(c128::of(3) * c128::from(0.5), c128::of(2));
+    assert_eq!(c128::of(3).mul_round(c128::from(-0.5), RoundingMode::TowardZero), c128::of(u128::MAX));
+    assert_eq!(c128::of(3) * c128::from(-0.5), c128::of(u128::MAX - 1));
+    assert_eq!(uc128::of(5) * uc128::from(0.5), uc128::of(2));
+    assert_eq!(uc128::of(5).mul_round(uc128::from(0.5), RoundingMode::NearestAway), uc128::of(3));
+    assert_eq!(ua128::of(1).mul_round(ua128::of(1), RoundingMode::TowardPositive), ua128::of(1));
+    assert_eq!(c16::MAX.mul_round(c16::from(2.0), RoundingMode::TowardZero), c16::MAX);
+
+    // Rounding to nearest removes the bias of truncation
+    let mut sum = 0i64;
+    for i in 0..256u16 {
+        sum += (c16::of(i) * c16::from(-0.5)).bits as i16 as i64;
+    }
+    assert_eq!(sum, -16320);
+
+    // c64 has 31 more fraction bits than c32
+    assert_eq!(c32::from(c64::of(1 << 30)), c32::of(0));
+    assert_eq!(c32::from(c64::of(3 << 30)), c32::of(2));
+    assert_eq!(c32::from_round(c64::of(3 << 30), RoundingMode::TowardZero), c32::of(1));
+    assert_eq!(c32::from_round(c64::of(1), RoundingMode::TowardPositive), c32::of(1));
+    assert_eq!(c32::from(c64::of((-(3i64 << 30)) as u64)), c32::of(-2i32 as u32));
+    assert_eq!(c32::from_round(c64::of((-(3i64 << 30)) as u64), RoundingMode::TowardZero), c32::of(-1i32 as u32));
+    assert_eq!(uc32::from(uc64::of(3 << 30)), uc32::of(2));
+    assert_eq!(uc32::from_round(uc64::of(1), RoundingMode::TowardPositive), uc32::of(1));
+    assert_eq!(uc8::from_round(uc128::of(1), RoundingMode::TowardPositive), uc8::of(1));
+    assert_eq!(c32::from(-1.25).rescale_round::<20>(RoundingMode::TowardZero), Fixed::<u32, 20>::from(-1.25));
+    assert_eq!(c32::of(1 << 7).rescale::<20>(), Fixed::<u32, 20>::of(0));
+    assert_eq!(c32::of(3 << 7).rescale::<20>(), Fixed::<u32, 20>::of(2));
+}
+
+#[test]
+/// Test fused multiply-add and multiply-subtract
+pub fn fused_tests() {
+    assert_eq!(c32::from(1.5).mul_add(c32::from(2.0), c32::from(0.25)), c32::from(3.25));
+    assert_eq!(c32::from(1.5).mul_sub(c32::from(2.0), c32::from(0.25)), c32::from(2.75));
+    // Half a grain survives when added before the final rounding
+    assert_eq!(c8::of(1) * c8::from(0.5) + c8::of(1), c8::of(1));
+    assert_eq!(c8::of(1).mul_add(c8::from(0.5), c8::of(1)), c8::of(2));
+    assert_eq!(c8::of(1).mul_sub(c8::from(0.5), c8::of(2)), c8::of(0xFE));
+    assert_eq!(c8::of(1).mul_add_round(c8::from(0.5), c8::of(0), RoundingMode::TowardPositive), c8::of(1));
+    assert_eq!(c8::of(1).mul_sub_round(c8::from(0.5), c8::of(0), RoundingMode::TowardNegative), c8::of(0));
+    // The intermediate product may exceed the range when the sum does not
+    assert_eq!(c16::MAX.mul_add(c16::from(2.0), c16::MIN), c16::of(0x7FFE));
+    assert_eq!(c16::MAX.mul_add(c16::from(2.0), c16::of(0)), c16::MAX);
+    assert_eq!(c16::MAX.mul_sub(c16::from(-2.0), c16::MAX), c16::MIN);
+    assert_eq!(uc16::from(1.0).mul_sub(uc16::from(1.0), uc16::from(2.0)), uc16::MIN);
+    assert_eq!(uc128::MAX.mul_add(uc128::from(1.0), uc128::of(1)), uc128::MAX);
+    assert_eq!(c128::of(3).mul_add(c128::from(-0.5), c128::of(0)), c128::of(u128::MAX - 1));
+    assert_eq!(c128::MIN.mul_add(c128::from(-1.0), c128::MIN), c128::of(0));
+}
+
+#[test]
+/// Test widening multiplication
+pub fn widening_tests() {
+    let product: c32 = c16::from(1.5).widening_mul(c16::from(-2.25));
+    assert_eq!(product, c32::from(-3.375));
+    let product: c32 = c16::GRN.widening_mul(c16::GRN);
+    assert_eq!(product, c32::of(4));
+    assert_eq!(c16::GRN.widening_mul::<26>(c16::GRN), Fixed::<u32, 26>::of(1));
+    assert_eq!(c16::MIN.widening_mul::<26>(c16::MIN), Fixed::<u32, 26>::from(16.0));
+    assert_eq!(c16::MIN.widening_mul::<28>(c16::MIN), c32::MAX);
+    let product: c16 = c8::MAX.widening_mul(c8::MIN);
+    assert_eq!(product, c16::from(-127.0 / 64.0 * 2.0));
+    let product: c128 = c64::MIN.widening_mul(c64::GRN);
+    assert_eq!(product, c128::from(-16.0 / 2f64.powi(59)));
+    let product: uc64 = uc32::MAX.widening_mul(uc32::MAX);
+    assert_eq!(product, uc64::MAX);
+    assert_eq!(uc32::MAX.widening_mul::<56>(uc32::MAX), UFixed::<u64, 56>::of(0xFFFF_FFFE_0000_0001));
+    let product: uc128 = uc64::from(1.5).widening_mul(uc64::from(2.5));
+    assert_eq!(product, uc128::from(3.75));
+    assert_eq!(c128::MIN.widening_mul(c128::MIN), i256::from(1) << 254);
+    assert_eq!(c128::from(-1.0).widening_mul(c128::from(1.5)), i256::from(-3) << 243);
+    assert_eq!(uc128::MAX.widening_mul(uc128::MAX), u256::from_mul(u128::MAX, u128::MAX));
+}
+
+#[test]
+/// Test comparisons and arithmetic between different layouts
+pub fn mixed_tests() {
+    // Comparisons are exact
+    assert!(c32::from(1.5) == c16::from(1.5));
+    assert!(c16::from(1.5) == c32::from(1.5));
+    assert!(c32::from(1.5) != c16::from(1.25));
+    assert!(c32::from(1.5) + c32::GRN > c16::from(1.5));
+    assert!(c16::from(1.5) < c32::from(1.5) + c32::GRN);
+    assert!(uc32::from(1.0) < c64::from(2.0));
+    assert!(c64::from(-2.0) < uc8::MIN);
+    assert!(uc8::MIN == c64::from(0.0));
+    assert!(uc32::MAX > c32::MAX);
+    assert!(c32::from(-0.5) < uc32::MIN);
+    assert!(uc128::MAX > ua64::MAX);
+    assert!(c8::MIN < ua128::GRN);
+    assert!(ua128::GRN < c128::GRN);
+    assert!(c128::MIN < c8::MIN);
+    assert!(UFixed::<u128, 0>::of(u128::MAX) > uc8::MAX);
+    assert!(UFixed::<u64, 64>::of(1) < UFixed::<u128, 0>::of(1));
+    assert!(UFixed::<u128, 128>::of(1) < UFixed::<u8, 0>::of(1));
+    assert!(Fixed::<u32, 20>::from(-1.25) == c16::from(-1.25));
+
+    // Results take the wider layout
+    assert_eq!(c32::from(1.5) + c16::from(0.25), c32::from(1.75));
+    assert_eq!(c16::from(0.25) + c32::from(1.5), c32::from(1.75));
+    assert_eq!(c16::from(0.25) - c32::from(1.5), c32::from(-1.25));
+    assert_eq!(c64::from(-3.0) * c8::from(0.5), c64::from(-1.5));
+    assert_eq!(c8::from(0.5) * c64::from(-3.0), c64::from(-1.5));
+    assert_eq!(uc32::from(1.5) + c16::from(-0.25), uc32::from(1.25));
+    assert_eq!(c16::from(-0.25) + uc32::from(1.5), uc32::from(1.25));
+    assert_eq!(c32::from(1.5) - uc32::from(2.0), c32::from(-0.5));
+    assert_eq!(uc32::from(2.0) - c32::from(1.5), c32::from(0.5));
+    assert_eq!(uc16::from(1.0) - c16::from(1.5), c16::from(-0.5));
+    assert_eq!(uc32::from(1.0) - c16::from(-0.5), uc32::from(1.5));
+    assert_eq!(c16::from(-0.5) - uc32::from(1.0), uc32::MIN);
+    assert_eq!(c128::from(1.0) + uc64::from(0.5), c128::from(1.5));
+    assert_eq!(uc128::from(1.0) * uc8::from(3.0), uc128::from(3.0));
+    assert_eq!(c8::from(1.0) + c64::MAX, c64::MAX);
+
+    // The exact result is rounded once, even when an operand is outside the result range
+    assert_eq!(c8::from(-1.5) + uc8::from(3.0), c8::from(1.5));
+    assert_eq!(uc8::from(3.0) + c8::from(-1.5), c8::from(1.5));
+    assert_eq!(uc8::from(3.0) - c8::from(1.5), c8::from(1.5));
+    assert_eq!(c8::from(0.5) - uc8::from(2.5), c8::from(-2.0));
+    assert_eq!(c32::from_int(-7) + ud16::from_int(10), c32::from_int(3));
+    assert_eq!(ud16::from_int(10) + c32::from_int(-7), c32::from_int(3));
+    assert_eq!(c32::from(-0.5) * ud16::from_int(10), c32::from(-5.0));
+    assert_eq!(uc32::from(15.0) + c16::from(-1.5), uc32::from(13.5));
+    assert_eq!(c16::from(-1.5) + uc32::from(1.0), uc32::MIN);
+    assert_eq!(c32::from(-7.5) + uc16::from(7.75), c32::from(0.25));
+    assert_eq!(c64::from(-1.0) * uc64::MAX, c64::MIN);
+    // Multiplication rounds only the exact product
+    assert_eq!(c32::GRN * c16::from(0.5), c32::of(0));
+    assert_eq!(c32::of(3) * c16::from(0.5), c32::of(2));
+    let (res, flags) = with_flags(|| c8::from(-1.5) + uc8::from(3.0));
+    assert_eq!(res, c8::from(1.5));
+    assert!(flags.is_empty());
+    let (_, flags) = with_flags(|| c16::from(-1.5) + uc32::from(1.0));
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+}
+
+#[test]
+/// Test scaling by plain integers and whole number conversion
+pub fn integer_tests() {
+    assert_eq!(c32::from_int(3), c32::from(3.0));
+    assert_eq!(c32::from_int(-8), c32::MIN);
+    assert_eq!(c32::from_int(100), c32::MAX);
+    assert_eq!(c32::from(3u32), c32::from(3.0));
+    assert_eq!(uc32::from_int(15), uc32::from(15.0));
+    assert_eq!(UFixed::<u128, 0>::from(u128::MAX), UFixed::<u128, 0>::MAX);
+    assert_eq!(Fixed::<u128, 0>::from_int(i128::MIN), Fixed::<u128, 0>::MIN);
+
+    assert_eq!(c32::from(-2.5).to_int(RoundingMode::TowardNegative), -3);
+    assert_eq!(c32::from(-2.5).to_int(RoundingMode::TowardZero), -2);
+    assert_eq!(c32::from(-2.5).to_int(RoundingMode::NearestEven), -2);
+    assert_eq!(c32::from(-2.5).to_int(RoundingMode::NearestAway), -3);
+    assert_eq!(c32::from(2.75).to_int(RoundingMode::NearestEven), 3);
+    assert_eq!(c8::MIN.to_int(RoundingMode::TowardZero), -2);
+    assert_eq!(Fixed::<u128, 0>::MIN.to_int(RoundingMode::NearestEven), i128::MIN);
+    assert_eq!(uc32::from(2.5).to_int(RoundingMode::TowardPositive), 3);
+    assert_eq!(ua128::MAX.to_int(RoundingMode::NearestEven), 1);
+    assert_eq!(ua128::MAX.to_int(RoundingMode::TowardZero), 0);
+
+    assert_eq!(c32::from(1.25) * 3, c32::from(3.75));
+    assert_eq!(c32::from(1.25) * -3, c32::from(-3.75));
+    assert_eq!(c32::from(1.25) * 100, c32::MAX);
+    assert_eq!(c32::from(-1.25) * 100, c32::MIN);
+    assert_eq!(c32::MIN * -1, c32::MAX);
+    assert_eq!(c32::from(3.75) / 3, c32::from(1.25));
+    assert_eq!(c32::from(3.75) / -3, c32::from(-1.25));
+    assert_eq!(c8::of(3) / 2, c8::of(2));
+    assert_eq!(c8::of(5) / -2, c8::of(0xFE));
+    assert_eq!(c8::from(-1.0) / 0, c8::MIN);
+    assert_eq!(uc16::from(1.5) * 2u32, uc16::from(3.0));
+    assert_eq!(uc16::from(3.0) / 2u32, uc16::from(1.5));
+    assert_eq!(uc16::of(5) / 2u32, uc16::of(2));
+    assert_eq!(uc16::of(5) / 0u32, uc16::MAX);
+    assert_eq!(c128::from(-1.5) * 2, c128::from(-3.0));
+    assert_eq!(uc128::MAX * 2u32, uc128::MAX);
+
+    assert_eq!(c32::from(-1.5) << 2, c32::from(-6.0));
+    assert_eq!(c32::from(-1.5) << 3, c32::MIN);
+    assert_eq!(c32::from(1.5) << 200, c32::MAX);
+    assert_eq!(c32::from(-1.5) >> 1, c32::from(-0.75));
+    assert_eq!(c8::of(3) >> 1, c8::of(2));
+    assert_eq!(c8::of(0xFD) >> 1, c8::of(0xFE));
+    assert_eq!(c8::MIN >> 200, c8::of(0));
+    assert_eq!(uc8::of(6) >> 2, uc8::of(2));
+    assert_eq!(uc8::of(6) << 7, uc8::MAX);
+    let mut val = c16::from(1.0);
+    val <<= 1;
+    val *= -1;
+    val /= 4;
+    val >>= 1;
+    assert_eq!(val, c16::from(-0.25));
+}
+
+#[test]
+/// Test correctly rounded roots
+pub fn roots_tests() {
+    assert_eq!(c32::from(2.0).sqrt(), c32::of(379625062));
+    assert_eq!(c32::from(2.0).rsqrt(), c32::of(189812531));
+    assert_eq!(c32::from(2.0).cbrt(), c32::of(338207482));
+    assert_eq!(c32::from(-3.0).cbrt(), -c32::of(387150921));
+    assert_eq!(c32::from(6.25).sqrt(), c32::from(2.5));
+    assert_eq!(c32::from(0.25).rsqrt(), c32::from(2.0));
+    assert_eq!(c32::from(-0.125).cbrt(), c32::from(-0.5));
+    assert_eq!(c32::from(0.0).sqrt(), c32::from(0.0));
+    assert_eq!(c32::from(0.0).rsqrt(), c32::MAX);
+    assert_eq!(c32::GRN.rsqrt(), c32::MAX);
+    assert_eq!(c32::from(-1.0).checked_sqrt(), None);
+    assert_eq!(c32::from(-1.0).checked_rsqrt(), None);
+    assert_eq!(c64::from(3.0).sqrt(), c64::of(0xddb3d742c26553a));
+    assert_eq!(c64::from(3.0).rsqrt(), c64::of(0x49e69d1640cc713));
+    assert_eq!(c64::from(10.0).cbrt(), c64::of(0x113c484138704ecb));
+    assert_eq!(c128::from(2.0).sqrt(), c128::of(0x5a827999fcef32422cbec4d9baa55f5));
+    assert_eq!(c128::from(2.0).rsqrt(), c128::of(0x2d413cccfe779921165f626cdd52afa));
+    assert_eq!(c128::from(2.0).cbrt(), c128::of(0x50a28be635ca2b888f76adc56f89434));
+    assert_eq!(c128::GRN.rsqrt(), c128::MAX);
+    assert_eq!(c128::MIN.cbrt(), -c128::of(0xcb2ff529eb71e41582cccd5a1ee26f8));
+    assert_eq!(uc128::MAX.sqrt(), uc128::of(0x20000000000000000000000000000000));
+    assert_eq!(uc128::MAX.cbrt(), uc128::of(0x10000000000000000000000000000000));
+    assert_eq!(uc128::MAX.rsqrt(), uc128::of(0x800000000000000000000000000000));
+    assert_eq!(uc128::of(1 << 60).rsqrt(), uc128::MAX);
+    assert_eq!(ua128::MAX.sqrt(), ua128::MAX);
+    assert_eq!(ua128::MAX.cbrt(), ua128::MAX);
+    assert_eq!(ua128::of(1 << 127).cbrt(), ua128::of(0xcb2ff529eb71e41582cccd5a1ee26f79));
+    assert_eq!(ua128::of(1 << 126).sqrt(), ua128::of(1 << 127));
+    assert_eq!(a8::MIN.cbrt(), a8::MIN);
+    assert_eq!(a8::MAX.sqrt(), a8::MAX);
+
+    // Every 16-bit result lies within half a grain of the exact root
+    for bits in 0..=u16::MAX {
+        let val = uc16::of(bits);
+        let num = (bits as u128) << 13;
+        let root = val.sqrt().bits as u128;
+        assert!((2 * root).saturating_sub(1).pow(2) <= 4 * num && 4 * num <= (2 * root + 1).pow(2));
+        let num = (bits as u128) << 26;
+        let root = val.cbrt().bits as u128;
+        assert!((2 * root).saturating_sub(1).pow(3) <= 8 * num && 8 * num <= (2 * root + 1).pow(3));
+        if bits > 0 && val.rsqrt() != uc16::MAX {
+            let root = val.rsqrt().bits as u128;
+            let num = 1u128 << 41;
+            assert!((2 * root - 1).pow(2) * bits as u128 <= num && num <= (2 * root + 1).pow(2) * bits as u128);
+        }
+    }
+}
+
+#[test]
+/// Test trigonometric functions against correctly rounded results
+pub fn trig_tests() {
+    let one = c32::from(1.0);
+    assert_eq!(one.sin(), c32::of(225880648));
+    assert_eq!(one.cos(), c32::of(145036296));
+    assert_eq!(one.sin_cos(), (c32::of(225880648), c32::of(145036296)));
+    assert_eq!(one.tan(), c32::of(418063453));
+    assert_eq!(one.atan(), c32::of(210828714));
+    assert_eq!(c32::from(0.5).asin(), c32::of(140552476));
+    assert_eq!(c32::from(-1.0).acos(), c32::of(843314857));
+    assert_eq!(c32::from(-1.0).atan2(c32::from(-1.0)), c32::of(-632486142i32 as u32));
+    assert_eq!(c32::from(-7.5).sin(), c32::of(-251792451i32 as u32));
+    assert_eq!(c32::from(3.0).cos(), c32::of(-265749087i32 as u32));
+    assert_eq!(c32::from(-1.5).tan(), c32::MIN);
+    assert_eq!(c32::from(-6.0).atan(), c32::of(-377325668i32 as u32));
+    assert_eq!(c32::from(0.0).sin_cos(), (c32::from(0.0), c32::from(1.0)));
+    assert_eq!(c32::from(0.0).atan2(c32::from(0.0)), c32::from(0.0));
+    assert_eq!(c32::from(0.0).atan2(c32::from(-2.0)), c32::from(-1.0).acos());
+    assert_eq!(c32::from(1.0).asin(), c32::from(-1.0).acos() >> 1);
+    assert_eq!(c32::from(1.5).checked_asin(), None);
+    assert_eq!(c32::from(-1.5).checked_acos(), None);
+    // Correctly rounded, where the constant is truncated
+    assert_eq!(c64::from(-1.0).acos(), c64::of(0x1921FB54442D1847));
+
+    assert_eq!(c128::from(2.0).sin(), c128::of(0x3a31edd5a3688bbf570903100139377));
+    assert_eq!(c128::from(2.0).cos(), -c128::of(0x1aa22657537204a4332f8acbb72b0d7));
+    assert_eq!(c128::from(1.0).atan2(c128::from(-2.0)), c128::of(0xab63739cbfad72cc16c9d9cde954cb3));
+    assert_eq!(c128::from(0.25).acos(), c128::of(0x545c0383052c93fc05595717b169eab));
+
+    // Arguments far from zero reduce exactly
+    assert_eq!(Fixed::<u64, 9>::from_int(1_000_000_000_000_000).sin(), Fixed::<u64, 9>::of(439));
+    assert_eq!(Fixed::<u128, 0>::MAX.sin_cos(), (Fixed::<u128, 0>::of(0), Fixed::<u128, 0>::of(1)));
+    // One is out of range for acutes
+    assert_eq!(a32::from(0.0).cos(), a32::MAX);
+    assert_eq!(a32::MIN.asin(), a32::MIN);
+}
+
+#[test]
+/// Test exponentials, logarithms and powers against correctly rounded results
+pub fn exp_tests() {
+    assert_eq!(c32::from(1.0).exp(), c32::of(0x2b7e1516));
+    assert_eq!(c32::from(2.0).ln(), c32::of(0xb17217f));
+    assert_eq!(c32::from(1.0).ln(), c32::from(0.0));
+    assert_eq!(c32::MIN.exp(), c32::of(90050));
+    assert_eq!(c32::from(3.0).exp(), c32::MAX);
+    assert_eq!(c32::from(3.0).checked_exp(), None);
+    assert_eq!(c32::from(0.0).ln(), c32::MIN);
+    assert_eq!(c32::from(0.0).checked_ln(), None);
+    assert_eq!(c32::from(-1.0).checked_log2(), None);
+    assert_eq!(c32::from(1.5).powf(c32::from(2.5)), c32::of(0x2c173fad));
+    // Powers of two and integer exponents are exact
+    assert_eq!(c64::from(3.0).exp2(), c64::from(8.0));
+    assert_eq!(c64::from(-3.0).exp2(), c64::from(0.125));
+    assert_eq!(c64::from(8.0).log2(), c64::from(3.0));
+    assert_eq!(c64::from(3.0).log2(), c64::of(0xcae00d1cfdeb43d));
+    assert_eq!(c64::from(1.0 / 3.0).exp2(), c64::of(0xa14517cc6b94568));
+    assert_eq!(c64::from(4.0).powf(c64::from(0.5)), c64::from(2.0));
+    assert_eq!(c128::from(1.0).exp(), c128::of(0xadf85458a2bb4a9aafdc5620273d3cf));
+    assert_eq!(c128::from(10.0).ln(), c128::of(0x935d8dddaaa8ac16ea56d62b82d30a3));
+
+    type F16 = Fixed<u32, 16>;
+    assert_eq!(F16::from(-20.0).exp(), F16::from(0.0));
+    assert_eq!(F16::from(100.0).log10(), F16::from(2.0));
+    assert_eq!(F16::from(0.001).log10(), F16::of(-196407i32 as u32));
+    assert_eq!(F16::from(1.5).powi(3), F16::from(3.375));
+    assert_eq!(F16::from(2.0).powi(-2), F16::from(0.25));
+    assert_eq!(F16::from(-2.0).powi(3), F16::from(-8.0));
+    assert_eq!(F16::from(-2.0).powi(16), F16::MAX);
+    assert_eq!(F16::from(-2.0).powi(15), F16::MIN);
+    assert_eq!(F16::from(-2.0).checked_powi(17), None);
+    assert_eq!(F16::from(0.0).powi(0), F16::from(1.0));
+    assert_eq!(F16::from(0.0).powi(-1), F16::MAX);
+    assert_eq!(F16::from(-2.0).powf(F16::from(3.0)), F16::from(-8.0));
+    assert_eq!(F16::from(-8.0).checked_powf(F16::from(0.5)), None);
+    assert_eq!(F16::from(0.0).checked_powf(F16::from(-1.0)), None);
+    assert_eq!(F16::from(0.0).powf(F16::from(0.0)), F16::from(1.0));
+
+    // Negative results saturate to zero for unsigned types
+    assert_eq!(uc32::from(0.5).ln(), uc32::from(0.0));
+    assert_eq!(uc32::from(0.5).checked_ln(), None);
+    assert_eq!(uc32::from(4.0).log2(), uc32::from(2.0));
+    assert_eq!(uc32::from(1.5).powi(2), uc32::from(2.25));
+    assert_eq!(uc32::from(2.0).powf(uc32::from(4.5)), uc32::MAX);
+    // One is out of range for acutes
+    assert_eq!(ua32::from(0.0).exp(), ua32::MAX);
+    assert_eq!(ua32::from(0.5).exp2().checked_log2(), None);
+
+    // Arguments of 2^64 or more saturate instead of losing their high bits
+    type Huge = UFixed<u128, 0>;
+    type Wide = Fixed<u128, 8>;
+    let (res, flags) = with_flags(|| Huge::of(1 << 64).exp());
+    assert_eq!(res, Huge::MAX);
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    assert_eq!(Huge::of(1 << 64).exp2(), Huge::MAX);
+    assert_eq!(Huge::of(1 << 64).checked_exp(), None);
+    assert_eq!(Huge::of(1 << 64).checked_exp2(), None);
+    assert_eq!(Huge::of(100).exp2(), Huge::of(1 << 100));
+    assert_eq!(Wide::from_int(1 << 64).exp2(), Wide::MAX);
+    assert_eq!(Wide::from_int(1 << 64).checked_exp(), None);
+    let (res, flags) = with_flags(|| Wide::from_int(-1 << 64).exp());
+    assert_eq!(res, Wide::of(0));
+    assert_eq!(flags, StatusFlags::UNDERFLOW | StatusFlags::INEXACT);
+    assert_eq!(Wide::from_int(-1 << 70).exp2(), Wide::of(0));
+    assert_eq!(Wide::from_int(-1 << 64).checked_exp2(), Some(Wide::of(0)));
+    let (res, flags) = with_flags(|| Wide::from(2.0).powf(Wide::from_int(1 << 64)));
+    assert_eq!(res, Wide::MAX);
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (res, flags) = with_flags(|| Wide::from(0.5).powf(Wide::from_int(1 << 64)));
+    assert_eq!(res, Wide::of(0));
+    assert_eq!(flags, StatusFlags::UNDERFLOW | StatusFlags::INEXACT);
+    assert_eq!(Wide::from(1.0).powf(Wide::from_int(1 << 100)), Wide::from(1.0));
+    assert_eq!(Huge::of(2).powf(Huge::of(1 << 64)), Huge::MAX);
+    assert_eq!(Huge::of(1 << 64).powf(Huge::of(2)), Huge::MAX);
+}
+
+#[test]
+/// Test value-level rounding, sign and ordering helpers, including MIN/MAX edge cases
+pub fn decompose_tests() {
+    let vals = [2.5, -2.5, 1.25, -1.75, 3.5, 0.0];
+    let expect = [
+        // floor, ceil, round, round_ties_even, trunc, fract
+        [2.0, 3.0, 3.0, 2.0, 2.0, 0.5],
+        [-3.0, -2.0, -3.0, -2.0, -2.0, -0.5],
+        [1.0, 2.0, 1.0, 1.0, 1.0, 0.25],
+        [-2.0, -1.0, -2.0, -2.0, -1.0, -0.75],
+        [3.0, 4.0, 4.0, 4.0, 3.0, 0.5],
+        [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    ];
+    for (val, exp) in vals.iter().zip(expect) {
+        let v = c16::from(*val);
+        assert_eq!([v.floor(), v.ceil(), v.round(), v.round_ties_even(), v.trunc(), v.fract()], exp.map(c16::from));
+    }
+    // Rounding MAX up saturates, and MIN is already whole
+    assert_eq!(c16::MAX.ceil(), c16::MAX);
+    assert_eq!(c16::MAX.round(), c16::MAX);
+    assert_eq!(c16::MAX.floor(), c16::from(3.0));
+    assert_eq!(c16::MIN.floor(), c16::MIN);
+    assert_eq!(c16::MIN.ceil(), c16::MIN);
+    assert_eq!(c16::MIN.fract(), c16::from(0.0));
+    assert_eq!(c16::MAX.fract(), c16::MAX - c16::from(3.0));
+
+    assert_eq!(c32::from(-1.5).abs(), c32::from(1.5));
+    assert_eq!(c32::MIN.abs(), c32::MAX);
+    assert_eq!(c32::from(-0.25).signum(), c32::from(-1.0));
+    assert_eq!(c32::from(0.0).signum(), c32::from(0.0));
+    assert_eq!(c32::from(1.5).copysign(c32::from(-0.5)), c32::from(-1.5));
+    assert_eq!(c32::MIN.copysign(c32::from(1.0)), c32::MAX);
+    assert_eq!(c32::MIN.copysign(c32::from(-1.0)), c32::MIN);
+    assert_eq!(c32::from(-1.5).abs_diff(c32::from(2.0)), c32::from(3.5));
+    assert_eq!(c32::MIN.abs_diff(c32::MAX), c32::MAX);
+    assert_eq!(c32::from(-1.5).min(c32::from(2.0)), c32::from(-1.5));
+    assert_eq!(c32::from(-1.5).max(c32::from(2.0)), c32::from(2.0));
+    assert_eq!(c32::from(5.0).clamp(c32::from(-1.0), c32::from(1.0)), c32::from(1.0));
+    assert_eq!(c32::MIN.clamp(c32::from(-1.0), c32::from(1.0)), c32::from(-1.0));
+    // One is out of range for acutes
+    assert_eq!(a16::from(0.5).ceil(), a16::MAX);
+    assert_eq!(a16::from(0.25).signum(), a16::MAX);
+    assert_eq!(a16::from(-0.25).floor(), a16::from(-1.0));
+    assert_eq!(a16::from(-0.25).fract(), a16::from(-0.25));
+
+    assert_eq!(uc16::from(2.5).round(), uc16::from(3.0));
+    assert_eq!(uc16::from(2.5).round_ties_even(), uc16::from(2.0));
+    assert_eq!(uc16::from(2.75).fract(), uc16::from(0.75));
+    assert_eq!(uc16::MAX.ceil(), uc16::MAX);
+    assert_eq!(uc16::from(1.5).abs_diff(uc16::from(4.0)), uc16::from(2.5));
+    assert_eq!(uc16::from(1.5).copysign(uc16::from(0.0)), uc16::from(1.5));
+    assert_eq!(ua128::from(0.5).round(), ua128::MAX);
+    assert_eq!(ua128::from(0.5).floor(), ua128::from(0.0));
+}
+
+#[test]
+/// Test ULP navigation and distances
+pub fn ulp_tests() {
+    assert_eq!(c32::from(1.0).next_up(), c32::of(c32::ONE + 1));
+    assert_eq!(c32::from(1.0).next_down(), c32::of(c32::ONE - 1));
+    assert_eq!(c32::from(0.0).next_down(), -c32::GRN);
+    assert_eq!(c32::MAX.next_up(), c32::MAX);
+    assert_eq!(c32::MIN.next_down(), c32::MIN);
+    assert_eq!(c32::MIN.next_up().next_down(), c32::MIN);
+    assert_eq!(c32::from(-1.0).ulp_distance(c32::from(1.0)), 2 << 28);
+    assert_eq!(c32::MIN.ulp_distance(c32::MAX), u32::MAX as u128);
+    assert_eq!(c128::MIN.ulp_distance(c128::MAX), u128::MAX);
+    assert!(c32::from(0.0).is_within_ulps(-c32::GRN, 1));
+    assert!(!c32::from(0.0).is_within_ulps(c32::from(1.0), 1 << 27));
+    // A correctly rounded result is within one ULP of a truncated reference
+    assert!(c64::from(-1.0).acos().is_within_ulps(c64::of(0x1921FB54442D1846), 1));
+
+    assert_eq!(uc16::MIN.next_down(), uc16::MIN);
+    assert_eq!(uc16::MAX.next_up(), uc16::MAX);
+    assert_eq!(uc16::MIN.next_up(), uc16::GRN);
+    assert_eq!(uc16::MAX.ulp_distance(uc16::MIN), u16::MAX as u128);
+    assert_eq!(uc128::MAX.ulp_distance(uc128::MIN), u128::MAX);
+    assert!(uc16::from(2.0).is_within_ulps(uc16::from(2.0), 0));
+}
+
+#[test]
+/// Test correctly rounded float conversions of 128-bit types, including ties, subnormals, signed zero and saturation
+pub fn float_conversion_tests() {
+    // Small values round straight to the grain, and back exactly
+    let tiny = 1.2345678901234567e-30;
+    assert_eq!(c128::from(tiny).bits, 6564089);
+    assert_eq!(f64::from(c128::from(tiny)), 1.234567926047155e-30);
+    assert_eq!(c128::from(f64::MIN_POSITIVE), c128::from(0.0));
+    assert_eq!(c128::from(-0.0), c128::from(0.0));
+    assert!(f64::from(c128::from(-0.0)).is_sign_positive());
+    // Ties round to even, both from and to floats
+    assert_eq!(c128::from(2.5 * 2f64.powi(-122)), c128::of(2));
+    assert_eq!(c128::from(-3.5 * 2f64.powi(-122)), -c128::of(4));
+    assert_eq!(f64::from(Fixed::<u128, 0>::of((1 << 53) + 1)), 9007199254740992.0);
+    assert_eq!(f64::from(Fixed::<u128, 0>::of((1 << 53) + 3)), 9007199254740996.0);
+    assert_eq!(f32::from(uc128::of((1 << 24) + 1)), 2f32.powi(-98));
+    // f32 rounds once, not through f64
+    assert_eq!(f32::from(uc128::of((1 << 53) + (1 << 29) + 1)), 1.0000001 * 2f32.powi(-69));
+    // Subnormals in both directions, and the f32 overflow threshold
+    assert_eq!(f32::from(ua128::of(3)), f32::from_bits(3 << 21));
+    assert_eq!(ua128::from(f32::from_bits(3 << 20)), ua128::of(2));
+    assert_eq!(ua128::from(f32::from_bits(5 << 20)), ua128::of(2));
+    assert_eq!(ua128::from(f32::from_bits(1)), ua128::of(0));
+    assert_eq!(c128::from(f64::from_bits(1)), c128::from(0.0));
+    assert_eq!(f32::from(UFixed::<u128, 0>::MAX), f32::INFINITY);
+    assert_eq!(f64::from(UFixed::<u128, 0>::MAX), 2f64.powi(128));
+    // Saturation and NaN
+    assert_eq!(c128::from(1e300), c128::MAX);
+    assert_eq!(c128::from(f64::NEG_INFINITY), c128::MIN);
+    assert_eq!(c128::from(-32.0), c128::MIN);
+    assert_eq!(uc128::from(-1.0), uc128::MIN);
+    assert_eq!(uc128::from(f32::INFINITY), uc128::MAX);
+    assert_eq!(c128::from(f64::NAN), c128::from(0.0));
+    assert_eq!(f64::from(c128::MIN), -32.0);
+    assert_eq!(f64::from(c128::MAX), 32.0);
+}
+
+#[cfg(feature = "f16")]
+#[test]
+/// Test correctly rounded conversions to and from f16
+pub fn f16_tests() {
+    assert_eq!(f16::from(c16::from(1.5)), 1.5f16);
+    assert_eq!(c16::from(1.5f16), c16::from(1.5));
+    // 2049 ties to even at 11 significant bits, 2051 rounds up
+    assert_eq!(f16::from(Fixed::<u32, 0>::of(2049)), 2048f16);
+    assert_eq!(f16::from(Fixed::<u32, 0>::of(2051)), 2052f16);
+    assert_eq!(f16::from(Fixed::<u32, 0>::MAX), f16::INFINITY);
+    assert_eq!(f16::from(ua32::of(1 << 8)), f16::from_bits(1));
+    assert_eq!(ua32::from(f16::from_bits(1)), ua32::of(1 << 8));
+    assert_eq!(c32::from(f16::NEG_INFINITY), c32::MIN);
+    assert_eq!(c32::from(f16::NAN), c32::from(0.0));
+}
+
+#[cfg(feature = "f128")]
+#[test]
+/// Test correctly rounded conversions to and from f128, which round-trip every c128 with at most 113 significant bits
+pub fn f128_tests() {
+    for val in [c128::MIN, c128::MAX, c128::GRN, c128::PI >> 11, -c128::E >> 11, c128::of(0x0123456789ABCDEF0123456789ABCD)] {
+        assert_eq!(c128::from(f128::from(val)), val);
+    }
+    for val in [uc128::MAX, uc128::GRN, uc128::PI >> 11] {
+        assert_eq!(uc128::from(f128::from(val)), val);
+    }
+    // Wider values round to the nearest f128
+    assert_eq!(c128::from(f128::from(c128::PI)), c128::of(c128::PI.bits & !0x7FF));
+    // 113 significant bits, so wider magnitudes round to even
+    assert_eq!(f128::from(UFixed::<u128, 0>::of((1 << 113) + 1)), f128::from(UFixed::<u128, 0>::of(1 << 113)));
+    assert_eq!(f128::from(UFixed::<u128, 0>::of((1 << 113) + 3)), f128::from(UFixed::<u128, 0>::of((1 << 113) + 4)));
+    assert_eq!(c128::from(1e-30f128).bits, 5316912);
+    assert_eq!(c128::from(f128::INFINITY), c128::MAX);
+}
+
+#[test]
+/// Test fallible conversions, which reject out-of-range values instead of clamping
+pub fn checked_conversion_tests() {
+    assert_eq!(c32::from_checked(1.5), Ok(c32::from(1.5)));
+    assert_eq!(c32::from_checked(8.0), Err(CertumError::Overflow));
+    assert_eq!(c32::from_checked(-8.0), Ok(c32::MIN));
+    assert_eq!(c32::from_checked(-8.5), Err(CertumError::Overflow));
+    assert_eq!(c32::from_checked(f64::INFINITY), Err(CertumError::Overflow));
+    assert_eq!(c32::from_checked(f64::NAN), Err(CertumError::NaN));
+    assert_eq!(c32::from_checked(f32::NAN), Err(CertumError::NaN));
+    assert_eq!(c32::from_checked(1e-10), Err(CertumError::Underflow));
+    assert_eq!(c32::from_checked(-0.0), Ok(c32::from(0.0)));
+    // Rounding is only an error for strict conversions
+    assert_eq!(c32::from_checked(0.1), Ok(c32::from(0.1)));
+    assert_eq!(c32::from_strict(0.1), Err(CertumError::PrecisionLoss));
+    // The f32 closest to 0.1 only needs 27 fraction bits
+    assert_eq!(c32::from_strict(0.1f32), Ok(c32::from(0.1f32)));
+    assert_eq!(c32::from_strict(0.375), Ok(c32::from(0.375)));
+    assert_eq!(c32::from_strict(1e-10), Err(CertumError::Underflow));
+    assert_eq!(uc32::from_checked(-1.0), Err(CertumError::Overflow));
+    assert_eq!(uc32::from_checked(-1e-10), Err(CertumError::Underflow));
+    assert_eq!(UFixed::<u128, 0>::from_checked(2f64.powi(128)), Err(CertumError::Overflow));
+    assert_eq!(UFixed::<u128, 0>::from_checked(2f64.powi(127)), Ok(UFixed::<u128, 0>::of(1 << 127)));
+
+    // Whole numbers
+    assert_eq!(c32::try_from(7i32), Ok(c32::from(7.0)));
+    assert_eq!(c32::try_from(-8i64), Ok(c32::MIN));
+    assert_eq!(c32::try_from(8u8), Err(CertumError::Overflow));
+    assert_eq!(uc32::try_from(-1i8), Err(CertumError::Overflow));
+    assert_eq!(Fixed::<u128, 0>::from_checked(u128::MAX), Err(CertumError::Overflow));
+    assert_eq!(Fixed::<u128, 0>::try_from(i128::MIN), Ok(Fixed::<u128, 0>::MIN));
+    assert_eq!(uc32::from_checked(15u32), Ok(uc32::from(15.0)));
+    assert_eq!(uc32::from_checked(16u32), Err(CertumError::Overflow));
+    assert_eq!(a32::try_from(1i32), Err(CertumError::Overflow));
+    assert_eq!(a32::try_from(-1i32), Ok(a32::MIN));
+
+    // Narrowing between certums
+    assert_eq!(c32::from_checked(c64::from(1.5)), Ok(c32::from(1.5)));
+    assert_eq!(c32::from_checked(c64::from(9.0)), Err(CertumError::Overflow));
+    assert_eq!(c32::from_checked(c64::GRN), Err(CertumError::Underflow));
+    assert_eq!(c32::from_checked(c64::PI), Ok(c32::from(c64::PI)));
+    assert_eq!(c32::from_strict(c64::PI), Err(CertumError::PrecisionLoss));
+    assert_eq!(c64::from_strict(c32::PI), Ok(c64::from(c32::PI)));
+    assert_eq!(uc16::from_checked(c64::from(-1.0)), Err(CertumError::Overflow));
+    assert_eq!(c8::from_checked(uc128::from(1.25)), Ok(c8::from(1.25)));
+    // The saturating From is unchanged
+    assert_eq!(c32::from(c64::from(9.0)), c32::MAX);
+    assert_eq!(c32::from(9.0), c32::MAX);
+
+    // Inherent forms that need no trait import
+    assert_eq!(c32::try_from_f64(0.5), Ok(c32::from(0.5)));
+    assert_eq!(c32::try_from_f64(9.0), Err(CertumError::Overflow));
+    assert_eq!(c32::try_from_f64(f64::NAN), Err(CertumError::NaN));
+    assert_eq!(uc32::try_from_f32(-1.0), Err(CertumError::Overflow));
+    assert_eq!(c128::try_from_f32(1e-30), Ok(c128::from(1e-30f32)));
+    assert_eq!(c32::try_convert(c64::from(9.0)), Err(CertumError::Overflow));
+    assert_eq!(c32::try_convert(c64::PI), Ok(c32::from(c64::PI)));
+    assert_eq!(c32::strict_convert(c64::PI), Err(CertumError::PrecisionLoss));
+    assert_eq!(uc8::try_convert(3u8), Ok(uc8::from(3.0)));
+
+    assert_eq!(CertumError::Overflow.to_string(), "value out of range of the target type");
+}
+
+#[test]
+/// Test the status flags raised by saturating arithmetic inside with_flags scopes
+pub fn flags_tests() {
+    // Exact results raise nothing
+    let (res, flags) = with_flags(|| c32::from(1.5) * c32::from(2.0) + c32::from(0.25) / c32::from(0.5));
+    assert_eq!(res, c32::from(3.5));
+    assert!(flags.is_empty());
+    assert_eq!(flags.check(), Ok(()));
+
+    // Saturation
+    let (res, flags) = with_flags(|| c32::from(6.0) + c32::from(6.0));
+    assert_eq!(res, c32::MAX);
+    assert!(flags.contains(StatusFlags::OVERFLOW | StatusFlags::INEXACT));
+    assert_eq!(flags.check(), Err(CertumError::Overflow));
+    let (res, flags) = with_flags(|| c32::from(-6.0) - c32::from(6.0));
+    assert_eq!(res, c32::MIN);
+    assert!(flags.contains(StatusFlags::OVERFLOW));
+    let (res, flags) = with_flags(|| uc32::from(1.0) - uc32::from(2.0));
+    assert_eq!(res, uc32::MIN);
+    assert!(flags.contains(StatusFlags::OVERFLOW));
+    let (_, flags) = with_flags(|| c32::from(-4.0) * c32::from(4.0));
+    assert!(flags.contains(StatusFlags::OVERFLOW));
+    let (_, flags) = with_flags(|| c32::from(-4.0) * c32::from(2.0));
+    assert!(flags.is_empty());
+
+    // Rounding
+    let (res, flags) = with_flags(|| c32::from(1.0) / c32::from(3.0));
+    assert_eq!(res, c32::of(89478485));
+    assert_eq!(flags, StatusFlags::INEXACT);
+    assert_eq!(flags.check(), Err(CertumError::PrecisionLoss));
+    let (_, flags) = with_flags(|| uc128::from(1.0) / uc128::from(3.0));
+    assert_eq!(flags, StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| c128::from(-1.0) / c128::from(4.0));
+    assert!(flags.is_empty());
+    let (_, flags) = with_flags(|| c128::PI * c128::E);
+    assert_eq!(flags, StatusFlags::INEXACT);
+    let (res, flags) = with_flags(|| c32::GRN * c32::GRN);
+    assert_eq!(res, c32::of(0));
+    assert_eq!(flags, StatusFlags::INEXACT | StatusFlags::UNDERFLOW);
+    assert_eq!(flags.check(), Err(CertumError::Underflow));
+
+    // Division by zero, including 0 / 0
+    let (res, flags) = with_flags(|| c32::from(1.0) / c32::of(0));
+    assert_eq!(res, c32::MAX);
+    assert_eq!(flags.check(), Err(CertumError::DivideByZero));
+    let (_, flags) = with_flags(|| uc32::of(0) / uc32::of(0));
+    assert_eq!(flags, StatusFlags::DIVIDE_BY_ZERO);
+    let (res, flags) = with_flags(|| c32::from(-1.0) / 0);
+    assert_eq!(res, c32::MIN);
+    assert_eq!(flags, StatusFlags::DIVIDE_BY_ZERO);
+    let (_, flags) = with_flags(|| c32::of(0).rsqrt());
+    assert_eq!(flags, StatusFlags::DIVIDE_BY_ZERO);
+
+    // Negation
+    let (res, flags) = with_flags(|| -c32::MIN);
+    assert_eq!(res, c32::MAX);
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| (-c32::MAX, c32::MIN.abs()));
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| -c32::MAX);
+    assert!(flags.is_empty());
+
+    // Float conversions
+    let (res, flags) = with_flags(|| c32::from(100.0));
+    assert_eq!(res, c32::MAX);
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| (uc128::from(f64::INFINITY), c8::from(f32::NEG_INFINITY)));
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| c32::from(0.1));
+    assert_eq!(flags, StatusFlags::INEXACT);
+    let (res, flags) = with_flags(|| c8::from(1e-10));
+    assert_eq!(res, c8::of(0));
+    assert_eq!(flags, StatusFlags::INEXACT | StatusFlags::UNDERFLOW);
+    let (_, flags) = with_flags(|| uc32::from(-1.0));
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| c32::from(f64::NAN));
+    assert_eq!(flags, StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| (c32::from(-7.5), c128::from(0.5f64.powi(100)), f64::from(c32::PI)));
+    assert!(flags.is_empty());
+
+    // Narrowing conversions between layouts, and rescaling
+    let (res, flags) = with_flags(|| c8::from(c32::from(3.0)));
+    assert_eq!(res, c8::MAX);
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| c8::from(c32::from(0.3)));
+    assert_eq!(flags, StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| c8::from(c32::GRN));
+    assert_eq!(flags, StatusFlags::INEXACT | StatusFlags::UNDERFLOW);
+    let (_, flags) = with_flags(|| uc8::from(uc32::MAX));
+    assert!(flags.contains(StatusFlags::OVERFLOW));
+    let (_, flags) = with_flags(|| (c8::from(c32::from(1.5)), c64::from(c32::MIN), c32::from(1.25).rescale::<16>()));
+    assert!(flags.is_empty());
+    let (_, flags) = with_flags(|| c32::PI.rescale::<8>());
+    assert_eq!(flags, StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| c32::from(7.0).rescale::<29>());
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| c32::from(1.0).to_string().parse::<c8>());
+    assert!(flags.is_empty());
+    let (_, flags) = with_flags(|| "0.1".parse::<c32>());
+    assert_eq!(flags, StatusFlags::INEXACT);
+
+    // Integer scaling
+    let (res, flags) = with_flags(|| c32::from(3.0) * 3);
+    assert_eq!(res, c32::MAX);
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| (c32::from(3.0) << 2, uc32::from(3.0) << 200, c32::from(-3.0) << 2));
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (res, flags) = with_flags(|| c32::from(1.0) / 3);
+    assert_eq!(res, c32::from(1.0) / c32::from(3.0));
+    assert_eq!(flags, StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| (c32::GRN >> 1, uc32::GRN / 3));
+    assert_eq!(flags, StatusFlags::INEXACT | StatusFlags::UNDERFLOW);
+    let (_, flags) = with_flags(|| (c32::from(1.5) * -2, c32::from(3.0) / 2, c32::from(3.0) >> 2, uc32::from(1.5) << 1, c32::from_int(-7)));
+    assert!(flags.is_empty());
+    let (_, flags) = with_flags(|| c32::from_int(100));
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+
+    // Fused operations, roots and elementary functions
+    let (res, flags) = with_flags(|| c32::from(3.0).mul_add(c32::from(3.0), c32::from(-1.0)));
+    assert_eq!(res, c32::MAX);
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| c32::GRN.mul_sub(c32::from(0.5), c32::of(0)));
+    assert_eq!(flags, StatusFlags::INEXACT | StatusFlags::UNDERFLOW);
+    let (_, flags) = with_flags(|| c32::from(1.5).mul_add(c32::from(2.0), c32::from(0.5)));
+    assert!(flags.is_empty());
+    let (_, flags) = with_flags(|| c32::GRN.rsqrt());
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| (c32::from(3.0).exp(), uc32::from(0.5).ln()));
+    assert_eq!(flags, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    let (_, flags) = with_flags(|| c32::of(0).ln());
+    assert_eq!(flags, StatusFlags::DIVIDE_BY_ZERO);
+
+    // Checked and wrapping forms do not raise flags
+    let (_, flags) = with_flags(|| (c32::from(6.0).checked_add(c32::from(6.0)), c32::from(6.0).wrapping_add(c32::from(6.0))));
+    assert!(flags.is_empty());
+
+    // Flags are sticky, and inner scopes also raise them in the enclosing scope
+    let ((_, inner), outer) = with_flags(|| {
+        let _ = c32::MAX + c32::MAX;
+        with_flags(|| c32::from(1.0) / c32::from(3.0))
+    });
+    assert_eq!(inner, StatusFlags::INEXACT);
+    assert_eq!(outer, StatusFlags::OVERFLOW | StatusFlags::INEXACT);
+    // Nothing is recorded outside of a scope
+    let _ = c32::MAX + c32::MAX;
+    assert!(with_flags(|| ()).1.is_empty());
+
+    // Errors
+    let err = CertumError::from("1.2.3".parse::<c32>().unwrap_err());
+    assert_eq!(err, CertumError::Parse(ParseErrorKind::InvalidDigit));
+    assert_eq!(err.to_string(), "1.2.3".parse::<c32>().unwrap_err().to_string());
+    let err: Box<dyn std::error::Error> = Box::new(CertumError::DivideByZero);
+    assert_eq!(err.to_string(), "attempt to divide by zero");
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+/// Test the num-traits implementations through generic code
+pub fn num_traits_tests() {
+    use num_traits::{Num, Bounded, Signed, FromPrimitive, ToPrimitive, CheckedAdd, CheckedDiv, Saturating, SaturatingMul};
+
+    /// Sum of squares written only against num-traits
+    fn sum_squares<T: Num + Copy>(vals: &[T]) -> T {
+        vals.iter().fold(T::zero(), |acc, &val| acc + val * val)
+    }
+    fn midpoint<T: Num + num_traits::NumCast + Copy>(a: T, b: T) -> T {
+        (a + b) * T::from(0.5).unwrap()
+    }
+    fn span<T: Bounded + Signed>() -> (T, T) {
+        (T::min_value().abs(), T::max_value().signum())
+    }
+
+    assert_eq!(sum_squares(&[c32::from(1.5), c32::from(-0.5), c32::from(2.0)]), c32::from(6.5));
+    assert_eq!(sum_squares(&[uc64::from(0.25), uc64::from(3.0)]), uc64::from(9.0625));
+    assert_eq!(midpoint(c64::from(-3.0), c64::from(4.0)), c64::from(0.5));
+    assert_eq!(span::<c16>(), (c16::MAX, c16::from(1.0)));
+
+    // Zero, One and parsing
+    assert_eq!(<c32 as num_traits::Zero>::zero(), c32::of(0));
+    assert!(num_traits::Zero::is_zero(&c32::of(0)));
+    assert_eq!(<uc128 as num_traits::One>::one(), uc128::from(1.0));
+    assert_eq!(<a32 as num_traits::One>::one(), a32::MAX);
+    assert_eq!(c32::from_str_radix("-1.25", 10), Ok(c32::from(-1.25)));
+    assert_eq!(c32::from_str_radix("1.25", 16).unwrap_err().kind(), &ParseErrorKind::InvalidDigit);
+
+    // Primitive conversions
+    assert_eq!(c32::from_i64(-8), Some(c32::MIN));
+    assert_eq!(c32::from_i64(8), None);
+    assert_eq!(uc32::from_i64(-1), None);
+    assert_eq!(c32::from_f64(0.1), Some(c32::from(0.1)));
+    assert_eq!(c32::from_f64(f64::NAN), None);
+    assert_eq!(Fixed::<u128, 0>::from_u128(u128::MAX), None);
+    assert_eq!(c32::from(-2.75).to_i64(), Some(-2));
+    assert_eq!(c32::from(-0.75).to_u64(), Some(0));
+    assert_eq!(c32::from(-1.75).to_u64(), None);
+    assert_eq!(UFixed::<u128, 0>::MAX.to_u128(), Some(u128::MAX));
+    assert_eq!(UFixed::<u128, 0>::MAX.to_i128(), None);
+    assert_eq!(c32::from(1.5).to_f64(), Some(1.5));
+    assert_eq!(<c64 as num_traits::NumCast>::from(3u8), Some(c64::from(3.0)));
+    assert_eq!(<c64 as num_traits::NumCast>::from(-0.25f32), Some(c64::from(-0.25)));
+    assert_eq!(<UFixed<u128, 0> as num_traits::NumCast>::from(u128::MAX), Some(UFixed::<u128, 0>::MAX));
+    assert_eq!(<c32 as num_traits::NumCast>::from(c64::from(2.5)), Some(c32::from(2.5)));
+    assert_eq!(<c32 as num_traits::NumCast>::from(100i32), None);
+
+    // Checked and saturating arithmetic
+    assert_eq!(CheckedAdd::checked_add(&c32::from(6.0), &c32::from(6.0)), None);
+    assert_eq!(CheckedDiv::checked_div(&c32::from(1.0), &c32::of(0)), None);
+    assert_eq!(CheckedDiv::checked_div(&c32::from(1.0), &c32::from(4.0)), Some(c32::from(0.25)));
+    assert_eq!(Saturating::saturating_sub(uc32::from(1.0), uc32::from(2.0)), uc32::MIN);
+    assert_eq!(SaturatingMul::saturating_mul(&c32::from(-4.0), &c32::from(4.0)), c32::MIN);
+
+    // Signed
+    assert_eq!(Signed::abs_sub(&c32::from(1.0), &c32::from(3.0)), c32::of(0));
+    assert_eq!(Signed::abs_sub(&c32::from(3.0), &c32::from(1.0)), c32::from(2.0));
+    assert!(Signed::is_negative(&c32::from(-0.5)));
+    assert!(!Signed::is_positive(&c32::of(0)));
+}
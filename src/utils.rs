@@ -1,3 +1,6 @@
+use std::cmp::Ordering;
+use crate::rounding::RoundingMode;
+
 // https://docs.rs/num-traits/latest/src/num_traits/float.rs.html#2049
 
 pub fn integer_decode_f32(f: f32) -> (u64, i16, i8) {
@@ -54,6 +57,148 @@ pub fn f64_split(mut float: f64) -> (u8, u64, u64) {
     (sign, integer_part, fractional_part)
 }
 
+/// Exact power of two as a Float64, usable in constants
+/// 
+/// Only valid for normal exponents, -1022 to 1023
+pub const fn f64_pow2(exp: i32) -> f64 {
+    f64::from_bits(((exp + 1023) as u64) << 52)
+}
+
+/// Move a sign and magnitude from one fraction width to another, rounding with `mode`.
+/// 
+/// Magnitudes above `max_pos` (or `max_neg` when negative) saturate to that limit
+pub fn rescale_parts(neg: bool, mag: u128, from: u32, to: u32, max_pos: u128, max_neg: u128, mode: RoundingMode) -> (bool, u128) {
+    let max = if neg { max_neg } else { max_pos };
+    let mag = if to >= from {
+        let shift = to - from;
+        if mag == 0 { return (neg, 0); }
+        if shift >= 128 || mag > (u128::MAX >> shift) { return (neg, max); }
+        mag << shift
+    } else {
+        round_shr(mag, from - to, neg, mode)
+    };
+    (neg, mag.min(max))
+}
+
+/// Whether a magnitude with `from` fraction bits is exactly representable with `to` fraction bits
+pub fn is_exact(mag: u128, from: u32, to: u32) -> bool {
+    if to >= from { return true; }
+    let shift = from - to;
+    if shift >= 128 { mag == 0 } else { mag & ((1 << shift) - 1) == 0 }
+}
+
+/// Whether `num * 2^frac / den` is an integer. `den` must be nonzero
+pub fn div_is_exact(num: u128, den: u128, frac: u32) -> bool {
+    if num == 0 { return true; }
+    let (mut a, mut b) = (num, den);
+    while b != 0 { (a, b) = (b, a % b); }
+    // The quotient is exact when what remains of the divisor is a power of two the shift cancels
+    let rest = den / a;
+    rest.is_power_of_two() && rest.trailing_zeros() <= frac
+}
+
+/// Compare two values given as sign, magnitude and fraction width, exactly
+pub fn cmp_parts(lhs: (bool, u128, u32), rhs: (bool, u128, u32)) -> Ordering {
+    let (lneg, lmag, lfrac) = lhs;
+    let (rneg, rmag, rfrac) = rhs;
+    let lneg = lneg && lmag != 0;
+    let rneg = rneg && rmag != 0;
+    if lneg != rneg { return if lneg { Ordering::Less } else { Ordering::Greater }; }
+    // Align both magnitudes to the wider fraction width, at most 128 bits apart
+    let frac = lfrac.max(rfrac);
+    let lwide = u256::from(lmag) << (frac - lfrac) as u128;
+    let rwide = u256::from(rmag) << (frac - rfrac) as u128;
+    if lneg { rwide.cmp(&lwide) } else { lwide.cmp(&rwide) }
+}
+
+/// Add two values given as sign, magnitude and fraction width, exactly
+/// 
+/// Returns the sign and magnitude of the sum with the wider fraction width
+pub fn add_parts(lhs: (bool, u128, u32), rhs: (bool, u128, u32)) -> (bool, u256, u32) {
+    let (lneg, lmag, lfrac) = lhs;
+    let (rneg, rmag, rfrac) = rhs;
+    // Aligned magnitudes stay below 2^256, and so does their sum
+    let frac = lfrac.max(rfrac);
+    let lwide = u256::from(lmag) << (frac - lfrac) as u128;
+    let rwide = u256::from(rmag) << (frac - rfrac) as u128;
+    if lneg == rneg {
+        (lneg, lwide + rwide, frac)
+    } else if lwide >= rwide {
+        (lneg, lwide - rwide, frac)
+    } else {
+        (rneg, rwide - lwide, frac)
+    }
+}
+
+/// Shift a magnitude right, rounding the discarded bits with `mode`.
+/// 
+/// `neg` is the sign of the value the magnitude belongs to. Rounding up past u128::MAX saturates
+pub fn round_shr(mag: u128, shift: u32, neg: bool, mode: RoundingMode) -> u128 {
+    if shift == 0 { return mag; }
+    let (trunc, rem, half) = if shift >= 128 {
+        (0, mag, if shift == 128 { 1u128 << 127 } else { u128::MAX })
+    } else {
+        (mag >> shift, mag & ((1u128 << shift) - 1), 1u128 << (shift - 1))
+    };
+    // Past 128 bits every remainder is below half
+    let cmp = if shift > 128 { Ordering::Less } else { rem.cmp(&half) };
+    let up = mode.round_up(neg, trunc & 1 == 1, cmp, rem != 0);
+    trunc.saturating_add(up as u128)
+}
+
+/// Round a sign and a magnitude with `frac` fraction bits to the raw bits of an IEEE float
+/// with `man` mantissa bits and `exp` exponent bits, to nearest with ties to even.
+/// 
+/// Results below the smallest normal are subnormal, zero keeps its sign and values past the largest finite float become infinite
+pub fn parts_to_float(neg: bool, mag: u128, frac: u32, man: u32, exp: u32) -> u128 {
+    let sign = (neg as u128) << (man + exp);
+    if mag == 0 { return sign; }
+    let bias = (1i64 << (exp - 1)) - 1;
+    // Power of two of the last mantissa bit, fixed at the subnormal spacing below the smallest normal
+    let top = mag.ilog2() as i64 - frac as i64;
+    let mut ulp = top.max(1 - bias) - man as i64;
+    let shift = ulp + frac as i64;
+    let mut mant = if shift <= 0 {
+        mag << -shift
+    } else {
+        round_shr(mag, shift.min(u32::MAX as i64) as u32, neg, RoundingMode::NearestEven)
+    };
+    // Rounding can carry into the next power of two
+    if mant >> (man + 1) != 0 {
+        mant >>= 1;
+        ulp += 1;
+    }
+    let max_exp = (1i64 << exp) - 1;
+    if mant >> man == 0 { return sign | mant; }
+    let biased = ulp + man as i64 + bias;
+    if biased >= max_exp { return sign | ((max_exp as u128) << man); }
+    sign | ((biased as u128) << man) | (mant & ((1 << man) - 1))
+}
+
+/// Split the raw bits of an IEEE float with `man` mantissa bits and `exp` exponent bits
+/// into a sign and a magnitude with the returned number of fraction bits.
+/// 
+/// Magnitudes of 2^128 or more, including infinities, saturate to u128::MAX. Returns None for NaN
+pub fn float_to_parts(bits: u128, man: u32, exp: u32) -> Option<(bool, u128, u32)> {
+    let neg = (bits >> (man + exp)) & 1 == 1;
+    let field = ((bits >> man) & ((1 << exp) - 1)) as i64;
+    let fraction = bits & ((1 << man) - 1);
+    let bias = (1i64 << (exp - 1)) - 1;
+    if field == (1 << exp) - 1 {
+        return if fraction == 0 { Some((neg, u128::MAX, 0)) } else { None };
+    }
+    // Subnormals share the exponent of the smallest normal, without the implicit bit
+    let (mant, pow) = if field == 0 {
+        (fraction, 1 - bias - man as i64)
+    } else {
+        (fraction | (1 << man), field - bias - man as i64)
+    };
+    if pow < 0 { return Some((neg, mant, (-pow) as u32)); }
+    if mant == 0 { return Some((neg, 0, 0)); }
+    if pow >= 128 || mant.leading_zeros() < pow as u32 { return Some((neg, u128::MAX, 0)); }
+    Some((neg, mant << pow, 0))
+}
+
 /// Clamp a u16 and round to a u8 properly.
 /// 
 /// Right-shift MSB to (16 - 9), carry case with + 1, right-shift MSB to make 8 bits. Clamp to u8
@@ -87,15 +232,15 @@ pub fn display_certums(value: f32) {
     let val128 = c128::from(value);
     println!("\nSigned Types: ");
     println!("0x{:02X}", val8.bits);
-    println!("{:.8}", f64::from(val8));
+    println!("{}", val8);
     println!("0x{:04X}", val16.bits);
-    println!("{:.16}", f64::from(val16));
+    println!("{}", val16);
     println!("0x{:08X}", val32.bits);
-    println!("{:.32}", f64::from(val32));
+    println!("{}", val32);
     println!("0x{:016X}", val64.bits);
-    println!("{:.64}", f64::from(val64));
+    println!("{}", val64);
     println!("0x{:032X}", val128.bits);
-    println!("{:.128}", f64::from(val128));
+    println!("{}", val128);
     let uval8 = uc8::from(value);
     let uval16 = uc16::from(value);
     let uval32 = uc32::from(value);
@@ -103,15 +248,15 @@ pub fn display_certums(value: f32) {
     let uval128 = uc128::from(value);
     println!("\nUnsigned Types: ");
     println!("0x{:02X}", uval8.bits);
-    println!("{:.8}", f64::from(uval8));
+    println!("{}", uval8);
     println!("0x{:04X}", uval16.bits);
-    println!("{:.16}", f64::from(uval16));
+    println!("{}", uval16);
     println!("0x{:08X}", uval32.bits);
-    println!("{:.32}", f64::from(uval32));
+    println!("{}", uval32);
     println!("0x{:016X}", uval64.bits);
-    println!("{:.64}", f64::from(uval64));
+    println!("{}", uval64);
     println!("0x{:032X}", uval128.bits);
-    println!("{:.128}", f64::from(uval128));
+    println!("{}", uval128);
 }
 
 pub trait QuickLog {